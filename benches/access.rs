@@ -0,0 +1,85 @@
+//! Compares access to cold (not yet evaluated) and warm (pre-evaluated) `Lazy` instances,
+//! as well as dereference and `force_then()` on both.
+//!
+//! Run with `cargo bench --features bench`.
+
+use std::hint::black_box;
+use std::time::{
+    Duration,
+    Instant
+};
+
+use sloth::Lazy;
+
+//
+// Constants
+//
+
+const ITERATIONS: u32 = 1_000_000;
+
+//
+// Benchmarks
+//
+
+fn main() {
+    report("cold access (evaluate + deref)", bench_cold_access());
+    report("warm access (preinitialized deref)", bench_warm_access());
+    report("cold access (evaluate + force_then)", bench_cold_force_then());
+    report("warm access (preinitialized force_then)", bench_warm_force_then());
+}
+
+fn bench_cold_access() -> Duration {
+    let start = Instant::now();
+
+    for i in 0..ITERATIONS {
+        let lazy_value = Lazy::new(|| black_box(i) * 2);
+
+        black_box(*lazy_value);
+    }
+
+    start.elapsed()
+}
+
+fn bench_warm_access() -> Duration {
+    let start = Instant::now();
+
+    for i in 0..ITERATIONS {
+        let lazy_value = Lazy::preinitialized(black_box(i) * 2);
+
+        black_box(*lazy_value);
+    }
+
+    start.elapsed()
+}
+
+fn bench_cold_force_then() -> Duration {
+    let start = Instant::now();
+
+    for i in 0..ITERATIONS {
+        let lazy_value = Lazy::new(|| black_box(i) * 2);
+
+        black_box(lazy_value.force_then(|value| *value));
+    }
+
+    start.elapsed()
+}
+
+fn bench_warm_force_then() -> Duration {
+    let start = Instant::now();
+
+    for i in 0..ITERATIONS {
+        let lazy_value = Lazy::preinitialized(black_box(i) * 2);
+
+        black_box(lazy_value.force_then(|value| *value));
+    }
+
+    start.elapsed()
+}
+
+//
+// Service
+//
+
+fn report(name: &str, elapsed: Duration) {
+    println!("{:<40} {:>8.2} ns/iter", name, elapsed.as_nanos() as f64 / f64::from(ITERATIONS));
+}
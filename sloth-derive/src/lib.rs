@@ -0,0 +1,128 @@
+//! This crate provides derive macros for the [`sloth`](https://docs.rs/sloth) crate.
+//! 
+//! It is not meant to be used directly: enable the `derive` feature of `sloth` instead,
+//! which re-exports the macros from this crate.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{
+    quote,
+    quote_spanned
+};
+use syn::spanned::Spanned;
+use syn::{
+    parse_macro_input,
+    Data,
+    DeriveInput,
+    Error,
+    Fields,
+    Index
+};
+
+//
+// Macros
+//
+
+/// Derives an inherent `force_all(&self)` method for a struct, whose fields are all
+/// [`Lazy`](https://docs.rs/sloth/latest/sloth/struct.Lazy.html) values, which evaluates
+/// each field, unless it has already been evaluated.
+///
+/// This is useful to warm up a group of lazy values before a latency-sensitive phase.
+/// Both structs with named fields and tuple structs are supported.
+/// ```
+/// use sloth::{Lazy, BoxedLazy, ForceAll};
+///
+/// #[derive(ForceAll)]
+/// struct Resources {
+///     greeting: BoxedLazy<String>,
+///     numbers:  BoxedLazy<Vec<i32>>
+/// }
+///
+/// let resources = Resources{
+///     greeting: Lazy::new_boxed(|| "hello".to_string()),
+///     numbers:  Lazy::new_boxed(|| vec![1, 2, 3])
+/// };
+///
+/// resources.force_all(); // both evaluators are invoked here
+///
+/// assert!(resources.greeting.is_evaluated());
+/// assert!(resources.numbers.is_evaluated());
+/// ```
+///
+/// Fields, which are not `Lazy`, are rejected at compile time:
+/// ```compile_fail
+/// use sloth::{Lazy, ForceAll};
+///
+/// #[derive(ForceAll)]
+/// struct Resources {
+///     greeting: Lazy<String>,
+///     count:    usize
+/// }
+/// ```
+///
+/// Deriving for enums and unions is a compile-time error as well:
+/// ```compile_fail
+/// use sloth::ForceAll;
+///
+/// #[derive(ForceAll)]
+/// enum Resources {
+///     Empty
+/// }
+/// ```
+#[proc_macro_derive(ForceAll)]
+pub fn derive_force_all(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand_force_all(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+//
+// Service
+//
+
+fn expand_force_all(input: &DeriveInput) -> Result<TokenStream2, Error> {
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _                  => return Err(Error::new_spanned(input, "ForceAll can only be derived for structs"))
+    };
+
+    let force_fields = match fields {
+        Fields::Named(named_fields) => named_fields.named
+            .iter()
+            .map(|field| {
+                let field_name = &field.ident;
+
+                // Spanning the call by the field type points type errors at the offending field
+                quote_spanned! { field.ty.span()=> ::sloth::Lazy::force(&self.#field_name); }
+            })
+            .collect::<Vec<_>>(),
+        Fields::Unnamed(unnamed_fields) => unnamed_fields.unnamed
+            .iter()
+            .enumerate()
+            .map(|(field_index, field)| {
+                let field_span  = field.ty.span();
+                let field_index = Index{
+                    index: field_index as u32,
+                    span:  field_span
+                };
+
+                quote_spanned! { field_span=> ::sloth::Lazy::force(&self.#field_index); }
+            })
+            .collect(),
+        Fields::Unit => Vec::new()
+    };
+
+    let struct_name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #struct_name #type_generics #where_clause {
+            /// Evaluates all lazy fields of this struct, which have not been evaluated yet.
+            pub fn force_all(&self) {
+                #(#force_fields)*
+            }
+        }
+    })
+}
@@ -0,0 +1,10 @@
+//! Checks the diagnostics produced by `#[derive(ForceAll)]` for unsupported input.
+//!
+//! Run with `TRYBUILD=overwrite cargo test --test ui` to update the `.stderr` snapshots.
+
+#[test]
+fn ui() {
+    let test_cases = trybuild::TestCases::new();
+
+    test_cases.compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,9 @@
+use sloth::{Lazy, ForceAll};
+
+#[derive(ForceAll)]
+enum Resources {
+    Greeting(Lazy<String>),
+    Empty
+}
+
+fn main() {}
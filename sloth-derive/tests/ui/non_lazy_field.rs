@@ -0,0 +1,9 @@
+use sloth::{Lazy, ForceAll};
+
+#[derive(ForceAll)]
+struct Resources {
+    greeting: Lazy<String>,
+    count:    usize
+}
+
+fn main() {}
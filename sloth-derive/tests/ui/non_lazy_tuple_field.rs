@@ -0,0 +1,6 @@
+use sloth::{Lazy, ForceAll};
+
+#[derive(ForceAll)]
+struct Resources(Lazy<String>, Vec<i32>);
+
+fn main() {}
@@ -0,0 +1,9 @@
+use sloth::ForceAll;
+
+#[derive(ForceAll)]
+union Resources {
+    count: usize,
+    ratio: f64
+}
+
+fn main() {}
@@ -0,0 +1,202 @@
+use std::future::{
+    self,
+    Future
+};
+use std::mem;
+use std::pin::Pin;
+use std::sync::{
+    Arc,
+    Mutex,
+    OnceLock,
+    TryLockError
+};
+use std::task::{
+    Context,
+    Poll,
+    Wake,
+    Waker
+};
+
+//
+// Constants
+//
+
+const EXPECT_FUTURE_STILL_PRESENT:    &str = "future must still be present until the value is cached";
+const EXPECT_FUTURE_MUTEX_UNPOISONED: &str = "future_mutex must not be poisoned, which only happens if future panicked";
+const EXPECT_WAKERS_MUTEX_UNPOISONED: &str = "wakers_mutex must not be poisoned, since it is never held while running user code";
+
+//
+// Interface
+//
+
+//
+// struct AsyncLazy<T, Fut>
+//
+
+/// An asynchronous counterpart of [`SyncLazy<T, Eval>`](struct.SyncLazy.html),
+/// whose value is produced by a future, which is awaited at most once.
+///
+/// The future is not polled until the value is first requested via [`get()`](struct.AsyncLazy.html#method.get).
+/// If several tasks request the value at the same time, only one of them drives the future at a time,
+/// while the others wait for the value without polling it.
+///
+/// This struct is only available with the `async` feature.
+/// ```
+/// use sloth::AsyncLazy;
+///
+/// async fn fetch_greeting() -> String {
+///     "hello".to_string()
+/// }
+///
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+/// let lazy_greeting = AsyncLazy::new(fetch_greeting());
+///
+/// assert_eq!(lazy_greeting.get().await, "hello"); // future is awaited here
+/// assert_eq!(lazy_greeting.get().await, "hello"); // but not here
+/// # });
+/// ```
+pub struct AsyncLazy<T, Fut>
+    where Fut: Future<Output = T>
+{
+    future_mutex: Mutex<Option<Pin<Box<Fut>>>>,
+    value_lock:   OnceLock<T>,
+    wakers:       Arc<WakerList>
+}
+
+//
+// Methods
+//
+
+impl<T, Fut> AsyncLazy<T, Fut>
+    where Fut: Future<Output = T>
+{
+    //
+    // Interface
+    //
+
+    /// Constructs a lazy `T` instance, whose value, if needed, will later be
+    /// obtained by awaiting `future` and cached.
+    ///
+    /// `future` will not be polled until the value is first requested.
+    pub fn new(future: Fut) -> Self {
+        Self{
+            future_mutex: Mutex::new(Some(Box::pin(future))),
+            value_lock:   OnceLock::new(),
+            wakers:       Arc::new(WakerList::new())
+        }
+    }
+
+    /// Immutably borrows the value, awaiting the future first if it has not completed yet.
+    ///
+    /// This will drive the future if no task has awaited its completion before.
+    /// Once the value is cached, the future is dropped and this method returns immediately.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the future has previously panicked while being polled.
+    pub async fn get(&self) -> &T {
+        future::poll_fn(|context| self.poll_get(context)).await
+    }
+
+    /// Immutably borrows the value, if it has already been cached, otherwise returns `None`.
+    ///
+    /// This does **not** poll the future.
+    #[must_use]
+    pub fn peek(&self) -> Option<&T> {
+        self.value_lock.get()
+    }
+
+    //
+    // Service
+    //
+
+    fn poll_get(&self, context: &mut Context<'_>) -> Poll<&T> {
+        if let Some(value) = self.value_lock.get() {
+            return Poll::Ready(value);
+        }
+
+        // Registered before trying the lock, so that this task is woken
+        // if another task is polling the future right now
+        self.wakers.register(context.waker());
+
+        let mut future_option = match self.future_mutex.try_lock() {
+            Ok(future_option)                  => future_option,
+            Err(TryLockError::WouldBlock)      => return Poll::Pending,
+            Err(TryLockError::Poisoned(_))     => panic!("{}", EXPECT_FUTURE_MUTEX_UNPOISONED)
+        };
+
+        if let Some(value) = self.value_lock.get() {
+            return Poll::Ready(value);
+        }
+
+        let shared_waker = Waker::from(Arc::clone(&self.wakers));
+
+        let poll_result = future_option
+            .as_mut()
+            .expect(EXPECT_FUTURE_STILL_PRESENT)
+            .as_mut()
+            .poll(&mut Context::from_waker(&shared_waker));
+
+        match poll_result {
+            Poll::Ready(value) => {
+                let value = self.value_lock.get_or_init(|| value);
+
+                *future_option = None;
+                drop(future_option);
+
+                self.wakers.wake_all();
+
+                Poll::Ready(value)
+            },
+            Poll::Pending => Poll::Pending
+        }
+    }
+}
+
+//
+// Service
+//
+
+struct WakerList {
+    wakers_mutex: Mutex<Vec<Waker>>
+}
+
+impl WakerList {
+    fn new() -> Self {
+        Self{
+            wakers_mutex: Mutex::new(Vec::new())
+        }
+    }
+
+    fn register(&self, waker: &Waker) {
+        let mut wakers = self.wakers_mutex
+            .lock()
+            .expect(EXPECT_WAKERS_MUTEX_UNPOISONED);
+
+        if !wakers.iter().any(|registered_waker| registered_waker.will_wake(waker)) {
+            wakers.push(waker.clone());
+        }
+    }
+
+    fn wake_all(&self) {
+        let wakers = mem::take(
+            &mut *self.wakers_mutex
+                .lock()
+                .expect(EXPECT_WAKERS_MUTEX_UNPOISONED)
+        );
+
+        wakers
+            .into_iter()
+            .for_each(Waker::wake);
+    }
+}
+
+impl Wake for WakerList {
+    fn wake(self: Arc<Self>) {
+        self.wake_all();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.wake_all();
+    }
+}
@@ -0,0 +1,61 @@
+use std::thread;
+
+use crate::Lazy;
+
+//
+// Functions
+//
+
+/// Evaluates each of `lazies` in order, maps its value with `mapping` and collects the results.
+///
+/// Evaluators and `mapping` are invoked on the current thread, strictly in the iteration order
+/// of `lazies`, each evaluator at most once.
+/// ```
+/// use sloth::{Lazy, force_map_collect};
+///
+/// let lazies = (1..=3).map(|value| Lazy::new(move || value * 10));
+///
+/// assert_eq!(force_map_collect(lazies, |value| value + 1), vec![11, 21, 31]);
+/// ```
+pub fn force_map_collect<T, Eval, U, I, F>(lazies: I, mut mapping: F) -> Vec<U>
+    where Eval: FnOnce() -> T,
+          I:    IntoIterator<Item = Lazy<T, Eval>>,
+          F:    FnMut(T) -> U
+{
+    lazies.into_iter()
+        .map(|lazy| mapping(lazy.unwrap()))
+        .collect()
+}
+
+/// Evaluates all of `lazies` in parallel, each on its own scoped thread, and waits for
+/// all evaluations to complete.
+///
+/// Since the threads are scoped, evaluators may borrow non-`'static` data from the caller.
+/// Each evaluator is invoked at most once, instances, which have already been evaluated,
+/// are left intact. If any of the evaluators panics, the panic is propagated to the caller
+/// after all threads have finished.
+/// ```
+/// use sloth::{Lazy, scoped_force_all};
+///
+/// let words = vec!["lorem", "ipsum", "dolor"];
+///
+/// let mut lazy_lengths: Vec<_> = words.iter()
+///     .map(|word| Lazy::new(move || word.len()))
+///     .collect();
+///
+/// scoped_force_all(&mut lazy_lengths);
+///
+/// assert!(lazy_lengths.iter().all(|lazy_length| **lazy_length == 5));
+/// ```
+pub fn scoped_force_all<T, Eval>(lazies: &mut [Lazy<T, Eval>])
+    where T:    Send,
+          Eval: FnOnce() -> T + Send
+{
+    thread::scope(|scope| {
+        for lazy in lazies.iter_mut() {
+            scope.spawn(move || {
+                lazy.as_ref();
+            });
+        }
+    });
+}
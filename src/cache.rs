@@ -0,0 +1,81 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+//
+// Interface
+//
+
+//
+// trait Cache<K, V>
+//
+
+/// Storage for values of type `V`, computed for keys of type `K`,
+/// which can be used by [`cached()`](fn.cached.html) to memoize evaluation results.
+///
+/// Methods of this trait take `&self`, so implementations are expected to use
+/// interior mutability. This allows the same cache to be shared between
+/// several call sites, e.g. an LRU cache or a cache backed by files on disk.
+///
+/// An implementation for `RefCell<HashMap<K, V>>` is provided for in-memory caching.
+pub trait Cache<K, V> {
+    /// Returns the value previously stored for `key`, if any.
+    fn get(&self, key: &K) -> Option<V>;
+
+    /// Stores `value` for `key`, possibly replacing a previously stored value.
+    fn insert(&self, key: K, value: V);
+}
+
+//
+// Cache impls
+//
+
+impl<K, V> Cache<K, V> for RefCell<HashMap<K, V>>
+    where K: Eq + Hash,
+          V: Clone
+{
+    fn get(&self, key: &K) -> Option<V> {
+        self.borrow().get(key).cloned()
+    }
+
+    fn insert(&self, key: K, value: V) {
+        self.borrow_mut().insert(key, value);
+    }
+}
+
+//
+// Functions
+//
+
+/// Returns the value stored in `cache` for `key` or, if there is none,
+/// obtains the value from `evaluator` and stores it in `cache`.
+///
+/// `evaluator` will only be invoked if `cache` holds no value for `key`.
+/// ```
+/// use std::cell::RefCell;
+/// use std::collections::HashMap;
+///
+/// use sloth::cached;
+///
+/// let cache = RefCell::new(HashMap::new());
+///
+/// let square = cached(&cache, 12, || 12 * 12); // evaluator is invoked here
+/// let same_square = cached(&cache, 12, || unreachable!()); // but not here
+///
+/// assert_eq!(square, same_square);
+/// ```
+pub fn cached<K, V, C, Eval>(cache: &C, key: K, evaluator: Eval) -> V
+    where C:    Cache<K, V> + ?Sized,
+          V:    Clone,
+          Eval: FnOnce() -> V
+{
+    if let Some(value) = cache.get(&key) {
+        return value;
+    }
+
+    let value = evaluator();
+
+    cache.insert(key, value.clone());
+
+    value
+}
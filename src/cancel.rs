@@ -0,0 +1,55 @@
+use alloc::sync::Arc;
+use core::sync::atomic::{
+    AtomicBool,
+    Ordering
+};
+
+//
+// Interface
+//
+
+//
+// struct CancelToken: Clone + Default
+//
+
+/// A flag, which can be shared between several owners, possibly on different threads,
+/// to cancel evaluation of lazy values constructed with [`Lazy::new_cancellable()`](struct.Lazy.html#method.new_cancellable).
+///
+/// All clones of a token refer to the same flag, so cancelling one of them cancels all of them.
+/// Once cancelled, a token cannot be reset.
+/// ```
+/// use sloth::{Lazy, CancelToken};
+///
+/// let token = CancelToken::new();
+/// let lazy_value = Lazy::new_cancellable(token.clone(), || unreachable!());
+///
+/// token.cancel();
+///
+/// assert_eq!(*lazy_value, None::<i32>);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken {
+    is_cancelled_flag: Arc<AtomicBool>
+}
+
+//
+// Methods
+//
+
+impl CancelToken {
+    /// Constructs a token, which is not cancelled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancels this token and all of its clones.
+    pub fn cancel(&self) {
+        self.is_cancelled_flag.store(true, Ordering::SeqCst);
+    }
+
+    /// Checks whether this token, or any of its clones, has been cancelled.
+    #[must_use]
+    pub fn is_cancelled(&self) -> bool {
+        self.is_cancelled_flag.load(Ordering::SeqCst)
+    }
+}
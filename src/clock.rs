@@ -0,0 +1,117 @@
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::{
+    Duration,
+    Instant
+};
+
+//
+// Interface
+//
+
+//
+// trait Clock
+//
+
+/// Source of the current time for time-dependent lazy values, such as [`TtlLazy`](struct.TtlLazy.html).
+///
+/// [`SystemClock`](struct.SystemClock.html) reports the actual time, while [`MockClock`](struct.MockClock.html)
+/// can be advanced manually, which makes time-dependent behaviour testable without sleeping.
+///
+/// Implementations for references and `Rc` pointers to clocks are provided,
+/// so that a single clock can be shared between several lazy values and the code controlling it.
+pub trait Clock {
+    /// Returns the current time, as seen by this clock.
+    fn now(&self) -> Instant;
+}
+
+//
+// struct SystemClock: Clock
+//
+
+/// A [`Clock`](trait.Clock.html), which reports the actual time via `Instant::now()`.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+//
+// struct MockClock: Clock
+//
+
+/// A [`Clock`](trait.Clock.html), whose time only changes when it is explicitly
+/// [advanced](struct.MockClock.html#method.advance).
+/// ```
+/// use std::time::Duration;
+///
+/// use sloth::{Clock, MockClock};
+///
+/// let clock = MockClock::new();
+/// let start = clock.now();
+///
+/// clock.advance(Duration::from_secs(5));
+///
+/// assert_eq!(clock.now() - start, Duration::from_secs(5));
+/// ```
+#[derive(Debug)]
+pub struct MockClock {
+    now_cell: Cell<Instant>
+}
+
+//
+// Clock impls
+//
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> Instant {
+        self.now_cell.get()
+    }
+}
+
+impl<C> Clock for &C
+    where C: Clock + ?Sized
+{
+    fn now(&self) -> Instant {
+        (**self).now()
+    }
+}
+
+impl<C> Clock for Rc<C>
+    where C: Clock + ?Sized
+{
+    fn now(&self) -> Instant {
+        (**self).now()
+    }
+}
+
+//
+// Trait impls
+//
+
+impl Default for MockClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+//
+// Methods
+//
+
+impl MockClock {
+    /// Constructs a mock clock, initially reporting the actual current time.
+    pub fn new() -> Self {
+        Self{
+            now_cell: Cell::new(Instant::now())
+        }
+    }
+
+    /// Moves the time reported by this clock forward by `duration`.
+    pub fn advance(&self, duration: Duration) {
+        self.now_cell.set(self.now_cell.get() + duration);
+    }
+}
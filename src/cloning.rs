@@ -0,0 +1,60 @@
+use crate::Lazy;
+
+//
+// Interface
+//
+
+//
+// struct CloningLazy<T, Eval>
+//
+
+/// Contains a lazily evaluated template value of some type `T`,
+/// which hands out owned clones of the template instead of references to it.
+///
+/// The template is evaluated the first time [`get()`](struct.CloningLazy.html#method.get) is called,
+/// every call returns an independent clone, so modifying the returned values does not affect the template.
+/// ```
+/// use sloth::CloningLazy;
+///
+/// let lazy_template = CloningLazy::new(|| vec![1, 2, 3]);
+///
+/// let mut first = lazy_template.get(); // evaluator is invoked here
+/// let second = lazy_template.get(); // but not here
+///
+/// first.push(4);
+///
+/// assert_eq!(second, vec![1, 2, 3]);
+/// ```
+pub struct CloningLazy<T, Eval>
+    where T:    Clone,
+          Eval: FnOnce() -> T
+{
+    template: Lazy<T, Eval>
+}
+
+//
+// Methods
+//
+
+impl<T, Eval> CloningLazy<T, Eval>
+    where T:    Clone,
+          Eval: FnOnce() -> T
+{
+    /// Constructs a lazy `T` instance, whose template value, if needed, will later be
+    /// obtained from `evaluator` and cached.
+    ///
+    /// `evaluator` will be invoked only the first time [`get()`](struct.CloningLazy.html#method.get) is called.
+    pub fn new(evaluator: Eval) -> Self {
+        Self{
+            template: Lazy::new(evaluator)
+        }
+    }
+
+    /// Returns a clone of the template value.
+    ///
+    /// This will invoke evaluator function if this method was not previously called.
+    #[must_use]
+    pub fn get(&self) -> T {
+        self.template.force().clone()
+    }
+}
@@ -0,0 +1,138 @@
+use core::cell::Cell;
+use core::ops::{
+    Deref,
+    DerefMut
+};
+
+use crate::Lazy;
+
+//
+// Interface
+//
+
+//
+// struct CountingLazy<T, Eval>: Deref<Target = T> + DerefMut + AsRef<T> + AsMut<T>
+//
+
+/// A [`Lazy<T, Eval>`](struct.Lazy.html), which counts how many times its value has been accessed,
+/// obtained via [`Lazy::new_counting()`](struct.Lazy.html#method.new_counting).
+///
+/// Every dereference and every call to `as_ref()` or `as_mut()` counts as an access,
+/// regardless of whether it invoked the evaluator function.
+/// ```
+/// use sloth::Lazy;
+///
+/// let lazy_value = Lazy::new_counting(|| 2 + 2);
+///
+/// assert_eq!(lazy_value.access_count(), 0);
+///
+/// let sum = *lazy_value + *lazy_value;
+///
+/// assert_eq!(lazy_value.access_count(), 2);
+/// ```
+pub struct CountingLazy<T, Eval>
+    where Eval: FnOnce() -> T
+{
+    lazy:              Lazy<T, Eval>,
+    access_count_cell: Cell<usize>
+}
+
+//
+// Trait impls
+//
+
+impl<T, Eval> Deref for CountingLazy<T, Eval>
+    where Eval: FnOnce() -> T
+{
+    type Target = T;
+
+    /// Immutable dereference, allowing access to the contained value
+    /// and incrementing the access count.
+    ///
+    /// This will invoke evaluator function if the value was not previously accessed.
+    fn deref(&self) -> &T {
+        self.count_access();
+
+        &self.lazy
+    }
+}
+
+impl<T, Eval> DerefMut for CountingLazy<T, Eval>
+    where Eval: FnOnce() -> T
+{
+    /// Mutable dereference, allowing access to the contained value
+    /// and incrementing the access count.
+    ///
+    /// This will invoke evaluator function if the value was not previously accessed.
+    fn deref_mut(&mut self) -> &mut T {
+        self.count_access();
+
+        &mut self.lazy
+    }
+}
+
+impl<T, Eval> AsRef<T> for CountingLazy<T, Eval>
+    where Eval: FnOnce() -> T
+{
+    /// Immutably borrows the evaluation result, incrementing the access count.
+    ///
+    /// This will invoke evaluator function if the value was not previously accessed.
+    fn as_ref(&self) -> &T {
+        self
+    }
+}
+
+impl<T, Eval> AsMut<T> for CountingLazy<T, Eval>
+    where Eval: FnOnce() -> T
+{
+    /// Mutably borrows the evaluation result, incrementing the access count.
+    ///
+    /// This will invoke evaluator function if the value was not previously accessed.
+    fn as_mut(&mut self) -> &mut T {
+        self
+    }
+}
+
+//
+// Methods
+//
+
+impl<T, Eval> CountingLazy<T, Eval>
+    where Eval: FnOnce() -> T
+{
+    //
+    // Interface
+    //
+
+    /// Returns the number of times the value of this instance has been accessed.
+    ///
+    /// This does **not** invoke evaluator function.
+    #[must_use]
+    pub fn access_count(&self) -> usize {
+        self.access_count_cell.get()
+    }
+
+    /// Consumes this instance and returns the underlying [`Lazy<T, Eval>`](struct.Lazy.html),
+    /// discarding the access count.
+    ///
+    /// This does **not** invoke evaluator function.
+    #[must_use]
+    pub fn into_lazy(self) -> Lazy<T, Eval> {
+        self.lazy
+    }
+
+    //
+    // Service
+    //
+
+    pub(crate) fn new(lazy: Lazy<T, Eval>) -> Self {
+        Self{
+            lazy,
+            access_count_cell: Cell::new(0)
+        }
+    }
+
+    fn count_access(&self) {
+        self.access_count_cell.set(self.access_count_cell.get() + 1);
+    }
+}
@@ -0,0 +1,27 @@
+use core::fmt;
+use core::error::Error;
+
+//
+// Interface
+//
+
+//
+// struct ValidationError: Error
+//
+
+/// The error cached by a lazy value constructed with [`Lazy::new_validated()`](struct.Lazy.html#method.new_validated),
+/// if the evaluation result was rejected by the validation function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ValidationError;
+
+//
+// Trait impls
+//
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("lazily evaluated value failed validation")
+    }
+}
+
+impl Error for ValidationError {}
@@ -0,0 +1,82 @@
+use alloc::{
+    boxed::Box,
+    vec::Vec
+};
+
+use crate::Lazy;
+
+//
+// Interface
+//
+
+//
+// struct FallbackLazy<'a, T>: Default
+//
+
+/// A builder for a lazy `Option<T>` value, which is evaluated by trying
+/// several evaluator functions in order until one of them returns `Some`.
+///
+/// Evaluators are invoked in the order they were passed to [`push()`](struct.FallbackLazy.html#method.push),
+/// the ones after the first successful evaluator are never invoked.
+/// If all evaluators return `None`, `None` is cached.
+/// ```
+/// use sloth::FallbackLazy;
+///
+/// let lazy_port = FallbackLazy::new()
+///     .push(|| std::env::var("SOME_UNSET_PORT_VARIABLE").ok())
+///     .push(|| Some("8080".to_string()))
+///     .push(|| unreachable!())
+///     .build();
+///
+/// assert_eq!(lazy_port.as_deref(), Some("8080"));
+/// ```
+pub struct FallbackLazy<'a, T> {
+    evaluators: Vec<Box<dyn FnOnce() -> Option<T> + 'a>>
+}
+
+//
+// Trait impls
+//
+
+impl<T> Default for FallbackLazy<'_, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+//
+// Methods
+//
+
+impl<'a, T> FallbackLazy<'a, T> {
+    /// Constructs a builder with no evaluators.
+    pub fn new() -> Self {
+        Self{
+            evaluators: Vec::new()
+        }
+    }
+
+    /// Adds `evaluator` to be tried after all previously added ones.
+    ///
+    /// This does **not** invoke `evaluator`.
+    #[must_use]
+    pub fn push<Eval>(mut self, evaluator: Eval) -> Self
+        where Eval: FnOnce() -> Option<T> + 'a
+    {
+        self.evaluators.push(Box::new(evaluator));
+
+        self
+    }
+
+    /// Consumes this builder and returns a lazy `Option<T>` value, which,
+    /// when first accessed, will try the evaluators in order and cache the first `Some`.
+    ///
+    /// This does **not** invoke any of the evaluators.
+    pub fn build(self) -> Lazy<Option<T>, impl FnOnce() -> Option<T> + 'a>
+        where T: 'a
+    {
+        let evaluators = self.evaluators;
+
+        Lazy::new(move || evaluators.into_iter().find_map(|evaluator| evaluator()))
+    }
+}
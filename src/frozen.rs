@@ -0,0 +1,71 @@
+use core::ops::Deref;
+use core::borrow::Borrow;
+
+//
+// Interface
+//
+
+//
+// struct Frozen<T>: Deref<Target = T> + AsRef<T> + Borrow<T>
+//
+
+/// Contains a finalized value of type `T`, which, unlike the value of [`Lazy<T, Eval>`](struct.Lazy.html),
+/// has already been evaluated and can no longer be modified.
+///
+/// A `Frozen` value does not carry an evaluator type, so it can be stored and passed around
+/// without spelling out closure types. It can be obtained from a `Lazy` via
+/// [`Lazy::map_freeze()`](struct.Lazy.html#method.map_freeze).
+/// ```
+/// use sloth::{Lazy, Frozen};
+///
+/// let frozen_len: Frozen<usize> = Lazy::new(|| "moo".to_string()).map_freeze(|string| string.len());
+///
+/// assert_eq!(*frozen_len, 3);
+/// ```
+pub struct Frozen<T> {
+    value: T
+}
+
+//
+// Trait impls
+//
+
+impl<T> Deref for Frozen<T> {
+    type Target = T;
+
+    /// Immutable dereference, allowing access to the contained value.
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T> AsRef<T> for Frozen<T> {
+    /// Immutably borrows the contained value.
+    fn as_ref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T> Borrow<T> for Frozen<T> {
+    /// Immutably borrows the contained value.
+    fn borrow(&self) -> &T {
+        &self.value
+    }
+}
+
+//
+// Methods
+//
+
+impl<T> Frozen<T> {
+    /// Constructs a `Frozen` instance, holding `value`.
+    pub fn new(value: T) -> Self {
+        Self{value}
+    }
+
+    /// Consumes this `Frozen` instance and extracts the contained value.
+    #[must_use]
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
@@ -0,0 +1,124 @@
+use core::ops::{
+    Deref,
+    DerefMut
+};
+
+use alloc::boxed::Box;
+
+use crate::Lazy;
+
+//
+// Types
+//
+
+type DropCallback<'a, T> = Box<dyn FnOnce(&mut T) + 'a>;
+
+//
+// Interface
+//
+
+//
+// struct LazyGuard<'a, T, Eval>: Deref<Target = T> + DerefMut + Drop
+//
+
+/// A scoped mutable borrow of the evaluated value of a [`Lazy<T, Eval>`](struct.Lazy.html),
+/// obtained via [`Lazy::guard()`](struct.Lazy.html#method.guard).
+///
+/// A callback, set with [`on_drop()`](struct.LazyGuard.html#method.on_drop), is invoked
+/// with the value once the guard goes out of scope, which allows committing or validating
+/// modifications made through the guard, e.g. marking the value as dirty.
+/// ```
+/// use std::cell::Cell;
+///
+/// use sloth::Lazy;
+///
+/// let is_dirty = Cell::new(false);
+/// let mut lazy_vec = Lazy::new(|| vec![1, 2, 3]);
+///
+/// {
+///     let mut guard = lazy_vec.guard().on_drop(|_| is_dirty.set(true));
+///
+///     guard.push(4);
+///
+///     assert!(!is_dirty.get());
+/// }
+///
+/// assert!(is_dirty.get());
+/// assert_eq!(*lazy_vec, vec![1, 2, 3, 4]);
+/// ```
+pub struct LazyGuard<'a, T, Eval>
+    where Eval: FnOnce() -> T
+{
+    lazy:    &'a mut Lazy<T, Eval>,
+    on_drop: Option<DropCallback<'a, T>>
+}
+
+//
+// Trait impls
+//
+
+impl<T, Eval> Deref for LazyGuard<'_, T, Eval>
+    where Eval: FnOnce() -> T
+{
+    type Target = T;
+
+    /// Immutable dereference, allowing access to the guarded value.
+    fn deref(&self) -> &T {
+        self.lazy
+    }
+}
+
+impl<T, Eval> DerefMut for LazyGuard<'_, T, Eval>
+    where Eval: FnOnce() -> T
+{
+    /// Mutable dereference, allowing modification of the guarded value.
+    fn deref_mut(&mut self) -> &mut T {
+        self.lazy
+    }
+}
+
+impl<T, Eval> Drop for LazyGuard<'_, T, Eval>
+    where Eval: FnOnce() -> T
+{
+    /// Releases the borrow, invoking the callback set with
+    /// [`on_drop()`](struct.LazyGuard.html#method.on_drop), if any.
+    fn drop(&mut self) {
+        if let Some(on_drop) = self.on_drop.take() {
+            on_drop(self.lazy);
+        }
+    }
+}
+
+//
+// Methods
+//
+
+impl<'a, T, Eval> LazyGuard<'a, T, Eval>
+    where Eval: FnOnce() -> T
+{
+    //
+    // Interface
+    //
+
+    /// Sets `callback` to be invoked with the guarded value when this guard is dropped,
+    /// replacing a previously set callback, if any.
+    #[must_use]
+    pub fn on_drop<F>(mut self, callback: F) -> Self
+        where F: FnOnce(&mut T) + 'a
+    {
+        self.on_drop = Some(Box::new(callback));
+
+        self
+    }
+
+    //
+    // Service
+    //
+
+    pub(crate) fn new(lazy: &'a mut Lazy<T, Eval>) -> Self {
+        Self{
+            lazy,
+            on_drop: None
+        }
+    }
+}
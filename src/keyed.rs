@@ -0,0 +1,95 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::Lazy;
+
+//
+// Types
+//
+
+type BoxedLazy<'a, V> = Lazy<V, Box<dyn FnOnce() -> V + 'a>>;
+
+//
+// Interface
+//
+
+//
+// struct KeyedLazy<'a, K, V>: Default
+//
+
+/// A collection of lazily evaluated values of type `V`, identified by keys of type `K`,
+/// each with its own evaluator function.
+///
+/// Accessing a value via [`get()`](struct.KeyedLazy.html#method.get) evaluates and caches only that value,
+/// evaluators of the other keys are not invoked until their values are accessed.
+/// ```
+/// use sloth::KeyedLazy;
+///
+/// let mut lazy_values = KeyedLazy::new();
+///
+/// lazy_values.insert("answer", || 42);
+/// lazy_values.insert("question", || unreachable!());
+///
+/// assert_eq!(lazy_values.get(&"answer"), Some(&42));
+/// ```
+pub struct KeyedLazy<'a, K, V> {
+    lazies: HashMap<K, BoxedLazy<'a, V>>
+}
+
+//
+// Trait impls
+//
+
+impl<K, V> Default for KeyedLazy<'_, K, V>
+    where K: Eq + Hash
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+//
+// Methods
+//
+
+impl<'a, K, V> KeyedLazy<'a, K, V>
+    where K: Eq + Hash
+{
+    /// Constructs an empty collection.
+    pub fn new() -> Self {
+        Self{
+            lazies: HashMap::new()
+        }
+    }
+
+    /// Adds a lazily evaluated value for `key`, replacing the previous one, if any.
+    ///
+    /// This does **not** invoke `evaluator`.
+    pub fn insert<Eval>(&mut self, key: K, evaluator: Eval)
+        where Eval: FnOnce() -> V + 'a,
+              V:    'a
+    {
+        let boxed_evaluator: Box<dyn FnOnce() -> V + 'a> = Box::new(evaluator);
+
+        self.lazies.insert(key, Lazy::new(boxed_evaluator));
+    }
+
+    /// Immutably borrows the value for `key`, or returns `None` if there is no value for `key`.
+    ///
+    /// This will invoke the evaluator function for `key` if its value was not previously accessed.
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.lazies
+            .get(key)
+            .map(|lazy| lazy.force())
+    }
+
+    /// Checks whether the value for `key` has already been evaluated.
+    ///
+    /// This does **not** invoke any evaluator functions.
+    #[must_use]
+    pub fn is_evaluated(&self, key: &K) -> bool {
+        self.lazies
+            .get(key)
+            .is_some_and(|lazy| lazy.is_evaluated())
+    }
+}
@@ -1,32 +1,143 @@
-use std::cell::{
-    Cell,
-    RefCell,
-    Ref,
-    RefMut
-};
-use std::ops::{
+use core::cell::Cell;
+use core::ops::{
     Deref,
     DerefMut
 };
-use std::borrow::{
+use core::borrow::{
     Borrow,
     BorrowMut
 };
+use core::cmp::Ordering;
+use core::fmt::{
+    self,
+    Debug,
+    Display
+};
+use core::hash::{
+    Hash,
+    Hasher
+};
+use core::iter::{
+    self,
+    FusedIterator,
+    Once
+};
+use core::mem;
+
+#[cfg(feature = "alloc")]
+use core::iter::{
+    Sum,
+    Product
+};
+#[cfg(feature = "alloc")]
+use alloc::{
+    borrow::ToOwned,
+    boxed::Box,
+    rc::Rc,
+    string::String,
+    vec::Vec
+};
+
+#[cfg(feature = "std")]
+use std::any::Any;
+#[cfg(feature = "std")]
+use std::io;
+#[cfg(feature = "std")]
+use std::panic::{
+    self,
+    AssertUnwindSafe,
+    UnwindSafe
+};
+#[cfg(feature = "std")]
+use std::sync::mpsc::{
+    self,
+    Receiver
+};
+#[cfg(feature = "std")]
+use std::time::{
+    Duration,
+    Instant
+};
+
+#[cfg(feature = "serde")]
+use serde::{
+    Serialize,
+    Serializer,
+    Deserialize,
+    Deserializer
+};
+
+use crate::{
+    CountingLazy,
+    Frozen,
+    SeededLazy,
+    ValidationError
+};
+#[cfg(feature = "alloc")]
+use crate::{
+    CancelToken,
+    LazyGuard
+};
+#[cfg(feature = "std")]
+use crate::TimedLazy;
+use crate::value_cell::ValueCell;
 
 //
 // Constants
 //
 
-const EXPECT_VALUE_CELL_INITIALIZED:  &str = "option in value_cell must be initialized at this point";
-const EXPECT_EVALUATOR_STILL_PRESENT: &str = "evaluator must still be present at this point";
-const EXPECT_VALUE_CELL_PTR_NOT_NULL: &str = "value_cell as ptr must not be null";
+const EXPECT_VALUE_CELL_INITIALIZED: &str = "value_cell must be initialized at this point";
+
+const EXPECT_EVALUATOR_TEMPLATE_PRESENT: &str = "Lazy must be constructed with new_reusable() to be re-armed with reset_reusing() after evaluation";
+const EXPECT_ARMED_ON_ACCESS:            &str = "Lazy holds neither a value nor an evaluator, since its evaluator panicked during a previous access or was taken";
+
+const REENTRANT_EVALUATION_MESSAGE: &str = "reentrant evaluation of Lazy detected: its evaluator accessed the same Lazy";
+
+#[cfg(feature = "alloc")]
+const EXPECT_UNZIPPED_COMPONENT_PRESENT: &str = "unzipped component must only be taken once by its own Lazy";
+
+const EXPECT_RESULT_OK: &str = "lazily evaluated result must be Ok";
+
+#[cfg(feature = "std")]
+const UNKNOWN_PANIC_MESSAGE: &str = "evaluator panicked with a non-string payload";
+
+//
+// Types
+//
+
+type DefaultEvaluator<T> = fn() -> T;
+
+/// A [`Lazy<T, Eval>`](struct.Lazy.html) with a boxed evaluator, as constructed by
+/// [`new_boxed()`](struct.Lazy.html#method.new_boxed).
+#[cfg(feature = "alloc")]
+pub type BoxedLazy<T> = Lazy<T, Box<dyn FnOnce() -> T>>;
 
 //
 // Interface
 //
 
 //
-// struct Lazy<T, Eval>: Deref<Target = T> + DerefMut + AsRef<T> + Borrow<T> + BorrowMut<T>
+// enum LazyState
+//
+
+/// Describes the state of a [`Lazy<T, Eval>`](struct.Lazy.html) instance,
+/// as reported by [`Lazy::state()`](struct.Lazy.html#method.state).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LazyState {
+    /// The value has not been evaluated yet, the evaluator is still present.
+    Unevaluated,
+    /// The evaluator is being invoked right now, e.g. when the state is inspected
+    /// by the evaluator itself.
+    Evaluating,
+    /// The evaluator has been invoked and the value is cached.
+    Evaluated,
+    /// The value has not been evaluated yet and there is no evaluator,
+    /// e.g. after a call to [`Lazy::take_evaluator()`](struct.Lazy.html#method.take_evaluator).
+    Unarmed
+}
+
+//
+// struct Lazy<T, Eval>: Deref<Target = T> + DerefMut + AsRef<T> + Borrow<T> + BorrowMut<T> + Clone + PartialEq + Eq + Hash + Debug + Display + IntoIterator<Item = T> + Serialize + Deserialize
 //
 
 /// Contains a value of some type `T`, lazily evaluated using a parameterless
@@ -98,11 +209,116 @@ const EXPECT_VALUE_CELL_PTR_NOT_NULL: &str = "value_cell as ptr must not be null
 /// 
 /// If a `Lazy` value is never dereferenced and none of its methods are called,
 /// its evaluator function will not be invoked at all.
-pub struct Lazy<T, Eval>
+/// 
+/// # Boxed evaluators
+/// 
+/// The `Eval` type parameter defaults to `fn() -> T`, so that `Lazy<T>` can be used as a field type
+/// for lazy values with evaluator functions, constructed e.g. with [`with_evaluator()`](struct.Lazy.html#method.with_evaluator).
+/// 
+/// With the `alloc` feature, [`BoxedLazy<T>`](type.BoxedLazy.html) can be used as a field type
+/// or an element type of a collection, holding lazy values with different evaluators, including closures.
+/// Such values are constructed with [`new_boxed()`](struct.Lazy.html#method.new_boxed):
+#[cfg_attr(feature = "alloc", doc = "```")]
+#[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+/// use sloth::{Lazy, BoxedLazy};
+/// 
+/// struct Config {
+///     name: BoxedLazy<String>
+/// }
+/// 
+/// let config = Config{name: Lazy::new_boxed(|| "sloth".to_string())};
+/// 
+/// assert_eq!(*config.name, "sloth");
+/// ```
+/// 
+/// # Borrowed values
+/// 
+/// Evaluators may return references, e.g. to static data, which makes `Lazy<&T, Eval>`
+/// a lazily performed lookup. No dedicated constructor is needed for this:
+/// ```
+/// use sloth::Lazy;
+/// 
+/// static COLOURS: [(&str, u32); 3] = [("red", 0xff0000), ("green", 0x00ff00), ("blue", 0x0000ff)];
+/// 
+/// let lazy_colour: Lazy<&'static (&str, u32), _> = Lazy::new(|| {
+///     COLOURS.iter().find(|(name, _)| *name == "green").unwrap()
+/// });
+/// 
+/// assert_eq!(lazy_colour.1, 0x00ff00);
+/// ```
+/// 
+/// # Panicking evaluators
+/// 
+/// If the evaluator panics, the panic propagates out of the method or the `*` deref operator,
+/// which invoked it, leaving no value cached. Since the evaluator is consumed by the call,
+/// any further access to an instance constructed with [`new()`](struct.Lazy.html#method.new) panics
+/// with a message saying so, much like accessing a poisoned `Mutex` does.
+/// 
+/// Instances constructed with [`new_reusable()`](struct.Lazy.html#method.new_reusable) are instead re-armed
+/// with a copy of the original evaluator, so the evaluation is retried on next access:
+/// ```
+/// use std::cell::Cell;
+/// use std::panic::{self, AssertUnwindSafe};
+/// 
+/// use sloth::Lazy;
+/// 
+/// let attempts = Cell::new(0);
+/// let lazy_value = Lazy::new_reusable(|| {
+///     attempts.set(attempts.get() + 1);
+///     assert!(attempts.get() > 1, "flaky evaluator fails the first time");
+///     42
+/// });
+/// 
+/// assert!(panic::catch_unwind(AssertUnwindSafe(|| *lazy_value)).is_err());
+/// assert_eq!(*lazy_value, 42);
+/// ```
+/// 
+/// # Drop order
+/// 
+/// When a `Lazy` is dropped, its evaluated value, if any, is dropped first,
+/// followed by its evaluator, if it has not been invoked, and then by the copy of the evaluator
+/// kept by instances constructed with [`new_reusable()`](struct.Lazy.html#method.new_reusable).
+/// Thus resources captured by the evaluator function always outlive the value it produced.
+/// 
+/// The evaluator, once invoked, is consumed by the call, dropping whatever it captured
+/// and did not move into the value, so an evaluated instance constructed
+/// with [`new()`](struct.Lazy.html#method.new) only holds the value.
+/// 
+/// `Lazy` does not implement `Drop` itself, relying on the above order of its fields instead,
+/// so each of the evaluator, the value and the evaluator template is dropped exactly once
+/// and a `Lazy` does not extend borrows captured by its evaluator beyond its last use.
+pub struct Lazy<T, Eval = DefaultEvaluator<T>>
+    where Eval: FnOnce() -> T
+{
+    value_cell:         ValueCell<T>,
+    evaluator_cell:     Cell<Option<Eval>>,
+    evaluator_template: Option<EvaluatorTemplate<Eval>>,
+    is_evaluating_cell: Cell<bool>
+}
+
+//
+// struct IntoIter<T, Eval>: Iterator<Item = T> + DoubleEndedIterator + ExactSizeIterator + FusedIterator
+//
+
+/// An iterator over the evaluation result of a [`Lazy<T, Eval>`](struct.Lazy.html),
+/// obtained via its `IntoIterator` implementation.
+/// 
+/// Like the iterator of an `Option` holding a value, it yields exactly one item.
+/// The evaluator function is not invoked by `into_iter()`, but only once the iterator is first advanced,
+/// if none of the methods or `*` deref operator of the `Lazy` were previously used.
+/// ```
+/// use sloth::Lazy;
+/// 
+/// let lazy_numbers: Vec<Lazy<u32, fn() -> u32>> = vec![Lazy::new(|| 2 + 2), Lazy::new(|| 3 * 3)];
+/// 
+/// let numbers: Vec<u32> = lazy_numbers.into_iter().flatten().collect();
+/// 
+/// assert_eq!(numbers, [4, 9]);
+/// ```
+pub struct IntoIter<T, Eval = DefaultEvaluator<T>>
     where Eval: FnOnce() -> T
 {
-    evaluator_cell: Cell<Option<Eval>>,
-    value_cell:     RefCell<Option<T>>
+    lazy: Option<Lazy<T, Eval>>
 }
 
 //
@@ -183,6 +399,184 @@ impl<T, Eval> BorrowMut<T> for Lazy<T, Eval>
     }
 }
 
+impl<T, Eval> Clone for Lazy<T, Eval>
+    where T:    Clone,
+          Eval: FnOnce() -> T + Clone
+{
+    /// Creates a copy of this instance, preserving its state.
+    /// 
+    /// If this instance has already been evaluated, the copy holds a clone of the value
+    /// and will never invoke an evaluator. Otherwise the copy holds a clone of the evaluator
+    /// and stays unevaluated, so the two instances will invoke their evaluators independently.
+    /// The copy of the evaluator kept by [`new_reusable()`](struct.Lazy.html#method.new_reusable) is cloned as well.
+    /// 
+    /// This does **not** invoke evaluator function.
+    fn clone(&self) -> Self {
+        let evaluator_option = self.evaluator_cell.take();
+        let evaluator_clone = evaluator_option.clone();
+
+        self.evaluator_cell.set(evaluator_option);
+
+        Self{
+            value_cell:         self.peek_impl().cloned().map_or_else(ValueCell::new, ValueCell::with_value),
+            evaluator_cell:     Cell::new(evaluator_clone),
+            evaluator_template: self.evaluator_template.clone(),
+            is_evaluating_cell: Cell::new(false)
+        }
+    }
+}
+
+impl<T, Eval, OtherEval> PartialEq<Lazy<T, OtherEval>> for Lazy<T, Eval>
+    where T:         PartialEq,
+          Eval:      FnOnce() -> T,
+          OtherEval: FnOnce() -> T
+{
+    /// Compares the evaluation results of both instances.
+    /// 
+    /// **Note** that, unlike most comparisons, this has a side effect:
+    /// it will invoke evaluator functions of **both** instances if none of their methods
+    /// or `*` deref operator were previously used.
+    /// To compare values only if they have already been evaluated, use [`peek()`](struct.Lazy.html#method.peek).
+    fn eq(&self, other: &Lazy<T, OtherEval>) -> bool {
+        self.as_ref_impl() == other.as_ref_impl()
+    }
+}
+
+impl<T, Eval> Eq for Lazy<T, Eval>
+    where T:    Eq,
+          Eval: FnOnce() -> T
+{
+}
+
+impl<T, Eval> Hash for Lazy<T, Eval>
+    where T:    Hash,
+          Eval: FnOnce() -> T
+{
+    /// Feeds the evaluation result into `state`, so that a `Lazy` hashes the same way as its value.
+    /// 
+    /// This will invoke evaluator function if none of the methods
+    /// or `*` deref operator were previously used.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_ref_impl().hash(state);
+    }
+}
+
+impl<T, Eval> Debug for Lazy<T, Eval>
+    where T:    Debug,
+          Eval: FnOnce() -> T
+{
+    /// Formats the evaluation result as `Lazy { evaluated: <value> }`,
+    /// or as `Lazy { <unevaluated> }` if this instance has not been evaluated yet.
+    /// 
+    /// This does **not** invoke evaluator function.
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.peek_impl() {
+            Some(value) => formatter.debug_struct("Lazy").field("evaluated", value).finish(),
+            None        => formatter.write_str("Lazy { <unevaluated> }")
+        }
+    }
+}
+
+impl<T, Eval> Display for Lazy<T, Eval>
+    where T:    Display,
+          Eval: FnOnce() -> T
+{
+    /// Formats the evaluation result exactly as `T` itself would be formatted,
+    /// including width, precision and other formatting parameters.
+    /// 
+    /// Unlike [`Debug`](#impl-Debug-for-Lazy%3CT,+Eval%3E), which is often used implicitly, e.g. in logs and assertions,
+    /// and thus never evaluates, `Display` is only used to print the value on purpose,
+    /// so this will invoke evaluator function if none of the methods
+    /// or `*` deref operator were previously used.
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(self.as_ref_impl(), formatter)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T, Eval> Serialize for Lazy<T, Eval>
+    where T:    Serialize,
+          Eval: FnOnce() -> T
+{
+    /// Serializes the evaluation result, exactly as `T` itself would be serialized.
+    /// 
+    /// This will invoke evaluator function if none of the methods
+    /// or `*` deref operator were previously used.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_ref_impl().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T, Eval> Deserialize<'de> for Lazy<T, Eval>
+    where T:    Deserialize<'de>,
+          Eval: FnOnce() -> T
+{
+    /// Deserializes a `T` and wraps it in an already evaluated `Lazy`,
+    /// like [`from_value()`](struct.Lazy.html#method.from_value) does.
+    /// 
+    /// The returned instance has no evaluator and will never invoke one.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize(deserializer).map(Self::new_evaluated)
+    }
+}
+
+impl<T, Eval> IntoIterator for Lazy<T, Eval>
+    where Eval: FnOnce() -> T
+{
+    type Item     = T;
+    type IntoIter = IntoIter<T, Eval>;
+
+    /// Consumes this [`Lazy<T, Eval>`](struct.Lazy.html) instance and returns an iterator,
+    /// which yields the evaluation result exactly once.
+    /// 
+    /// This does **not** invoke evaluator function. It will be invoked, if needed,
+    /// once the returned iterator is first advanced.
+    fn into_iter(self) -> IntoIter<T, Eval> {
+        IntoIter{
+            lazy: Some(self)
+        }
+    }
+}
+
+impl<T, Eval> Iterator for IntoIter<T, Eval>
+    where Eval: FnOnce() -> T
+{
+    type Item = T;
+
+    /// Yields the evaluation result, if it has not been yielded yet.
+    /// 
+    /// This will invoke evaluator function the first time this method is called, if none of the methods
+    /// or `*` deref operator of the originating `Lazy` were previously used.
+    fn next(&mut self) -> Option<T> {
+        self.lazy.take().map(Lazy::unwrap)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = usize::from(self.lazy.is_some());
+
+        (len, Some(len))
+    }
+}
+
+impl<T, Eval> DoubleEndedIterator for IntoIter<T, Eval>
+    where Eval: FnOnce() -> T
+{
+    fn next_back(&mut self) -> Option<T> {
+        self.next()
+    }
+}
+
+impl<T, Eval> ExactSizeIterator for IntoIter<T, Eval>
+    where Eval: FnOnce() -> T
+{
+}
+
+impl<T, Eval> FusedIterator for IntoIter<T, Eval>
+    where Eval: FnOnce() -> T
+{
+}
+
 //
 // Methods
 //
@@ -201,11 +595,111 @@ impl<T, Eval> Lazy<T, Eval>
     /// is dereferenced or one of its methods is invoked.
     pub fn new(evaluator: Eval) -> Self {
         Self{
-            evaluator_cell: Cell::new(Some(evaluator)),
-            value_cell:     RefCell::new(None)
+            value_cell:         ValueCell::new(),
+            evaluator_cell:     Cell::new(Some(evaluator)),
+            evaluator_template: None,
+            is_evaluating_cell: Cell::new(false)
         }
     }
 
+    /// Constructs a lazy `T` instance, like [`new()`](struct.Lazy.html#method.new) does,
+    /// but also invokes `hook` with `label` right before `evaluator`.
+    /// 
+    /// This allows instrumenting evaluation of lazy values, e.g. for logging or metrics.
+    /// Neither `hook` nor `evaluator` will be invoked until the returned instance
+    /// is dereferenced or one of its methods is invoked.
+    pub fn new_with_hook<Hook>(label: &'static str, hook: Hook, evaluator: Eval) -> Lazy<T, impl FnOnce() -> T>
+        where Hook: FnOnce(&'static str)
+    {
+        Lazy::new(move || {
+            hook(label);
+
+            evaluator()
+        })
+    }
+
+    /// Constructs a lazy `T` instance, like [`new()`](struct.Lazy.html#method.new) does,
+    /// together with a `Receiver`, which gets a message once the value is evaluated.
+    /// 
+    /// This allows other parts of a program, possibly running on other threads, to react
+    /// to initialization of the lazy value. No message is sent if the value is never evaluated;
+    /// the sender is dropped together with the returned instance or after evaluation.
+    /// 
+    /// `evaluator` will not be invoked until the returned instance
+    /// is dereferenced or one of its methods is invoked.
+    #[cfg(feature = "std")]
+    pub fn on_eval_notify(evaluator: Eval) -> (Lazy<T, impl FnOnce() -> T>, Receiver<()>) {
+        let (sender, receiver) = mpsc::channel();
+
+        let lazy = Lazy::new(move || {
+            let value = evaluator();
+
+            sender.send(()).ok();
+
+            value
+        });
+
+        (lazy, receiver)
+    }
+
+    /// Constructs a lazy `T` instance, like [`new()`](struct.Lazy.html#method.new) does,
+    /// wrapped in a [`CountingLazy<T, Eval>`](struct.CountingLazy.html), which counts accesses to its value.
+    /// 
+    /// `evaluator` will be invoked only the first time the returned instance is accessed.
+    pub fn new_counting(evaluator: Eval) -> CountingLazy<T, Eval> {
+        CountingLazy::new(Lazy::new(evaluator))
+    }
+
+    /// Constructs a lazy `Option<T>` instance, which, when accessed, checks `token`
+    /// and, unless it has been cancelled, obtains the value from `evaluator`.
+    /// 
+    /// If `token` has been cancelled by the time of the first access, `evaluator` is dropped
+    /// without being invoked and `None` is cached, otherwise `Some` with the value is cached.
+    /// Cancelling `token` after evaluation does not affect the cached value.
+    /// 
+    /// `evaluator` will not be invoked until the returned instance
+    /// is dereferenced or one of its methods is invoked.
+    #[cfg(feature = "alloc")]
+    pub fn new_cancellable(token: CancelToken, evaluator: Eval) -> Lazy<Option<T>, impl FnOnce() -> Option<T>> {
+        Lazy::new(move || {
+            if token.is_cancelled() {
+                None
+            } else {
+                Some(evaluator())
+            }
+        })
+    }
+
+    /// Constructs a lazy `T` instance, like [`new()`](struct.Lazy.html#method.new) does,
+    /// wrapped in a [`TimedLazy<T, Eval>`](struct.TimedLazy.html), which records how long evaluation took.
+    /// 
+    /// `evaluator` will be invoked only the first time the returned instance is accessed.
+    #[cfg(feature = "std")]
+    pub fn timed(evaluator: Eval) -> TimedLazy<T, Eval> {
+        TimedLazy::new(Lazy::new(evaluator))
+    }
+
+    /// Constructs a lazy `Result<T, ValidationError>` instance, which, when accessed,
+    /// obtains a value from `evaluator` and checks it with `validate`.
+    /// 
+    /// If `validate` returns `true`, `Ok` with the value is cached,
+    /// otherwise the value is dropped and `Err(ValidationError)` is cached.
+    /// Neither `evaluator` nor `validate` will be invoked until the returned instance
+    /// is dereferenced or one of its methods is invoked.
+    pub fn new_validated<V>(evaluator: Eval, validate: V) -> Lazy<Result<T, ValidationError>, impl FnOnce() -> Result<T, ValidationError>>
+        where V: FnOnce(&T) -> bool
+    {
+        Lazy::new(move || {
+            let value = evaluator();
+
+            if validate(&value) {
+                Ok(value)
+            } else {
+                Err(ValidationError)
+            }
+        })
+    }
+
     /// Immutably borrows the evaluation result.
     /// 
     /// This will invoke evaluator function if none of the methods
@@ -214,16 +708,8 @@ impl<T, Eval> Lazy<T, Eval>
     /// **`value_ref()` will be removed in sloth 0.3.0. [`as_ref()` or immutable * dereference](struct.Lazy.html#implementations) should be used instead.**
     #[must_use]
     #[deprecated(since = "0.2.0", note = "will be removed in sloth 0.3.0; please use as_ref() or * deref operator instead")]
-    pub fn value_ref(&self) -> Ref<'_, T> {
-        self.init_once();
-
-        // Returns a Ref to the T instance contained within Option<T> referenced by value_cell
-        Ref::map(
-            self.value_cell.borrow(),
-            |value_option| {
-                value_option.as_ref().expect(EXPECT_VALUE_CELL_INITIALIZED)
-            }
-        )
+    pub fn value_ref(&self) -> &T {
+        self.as_ref_impl()
     }
 
     /// Mutably borrows the evaluation result.
@@ -234,16 +720,60 @@ impl<T, Eval> Lazy<T, Eval>
     /// **`value_mut()` will be removed in sloth 0.3.0. [`as_mut()` or mutable * dereference](struct.Lazy.html#implementations) should be used instead.**
     #[must_use]
     #[deprecated(since = "0.2.0", note = "will be removed in sloth 0.3.0; please use as_mut() or * deref operator instead")]
-    pub fn value_mut(&mut self) -> RefMut<'_, T> {
-        self.init_once();
+    pub fn value_mut(&mut self) -> &mut T {
+        self.as_mut_impl()
+    }
 
-        // Returns a RefMut to the T instance contained within Option<T> referenced by value_cell_mut
-        RefMut::map(
-            self.value_cell.borrow_mut(),
-            |value_option| {
-                value_option.as_mut().expect(EXPECT_VALUE_CELL_INITIALIZED)
-            }
-        )
+    /// Evaluates this instance, if it has not been evaluated yet, and immutably borrows the evaluation result.
+    /// 
+    /// This is equivalent to [`as_ref()`](struct.Lazy.html#method.as_ref), but documents the intent
+    /// to evaluate the value at a known point. Calling `force()` ahead of a latency-sensitive section,
+    /// such as a hot loop, is the recommended way to move the evaluation cost out of it.
+    /// ```
+    /// use sloth::Lazy;
+    /// 
+    /// let lazy_table = Lazy::new(|| (0..256).map(|i| i * i).collect::<Vec<u32>>());
+    /// 
+    /// lazy_table.force(); // evaluator is invoked here
+    /// 
+    /// let sum: u32 = (0..256).map(|i| lazy_table[i]).sum(); // and not in the loop
+    /// ```
+    /// 
+    /// This will invoke evaluator function if none of the methods
+    /// or `*` deref operator were previously used.
+    pub fn force(&self) -> &T {
+        self.as_ref_impl()
+    }
+
+    /// Immutably borrows the evaluation result, if this instance has already been evaluated,
+    /// otherwise stores `value` as the evaluation result, dropping the evaluator, and borrows it.
+    /// 
+    /// This allows installing a value computed elsewhere, e.g. when filling a cache,
+    /// without discarding one, which has already been evaluated. If that is the case, `value` is dropped.
+    /// ```
+    /// use sloth::Lazy;
+    /// 
+    /// let lazy_value = Lazy::new(|| 42);
+    /// 
+    /// assert_eq!(*lazy_value.get_or_set(7), 7); // evaluator is dropped here
+    /// assert_eq!(*lazy_value.get_or_set(13), 7);
+    /// ```
+    /// 
+    /// This does **not** invoke evaluator function.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if called from the evaluator function of this instance.
+    pub fn get_or_set(&self, value: T) -> &T {
+        assert!(!self.is_evaluating(), "{}", REENTRANT_EVALUATION_MESSAGE);
+
+        if !self.is_initialized() {
+            self.value_cell.get_or_init(|| value);
+
+            drop(self.evaluator_cell.take());
+        }
+
+        self.as_ref_impl()
     }
 
     /// Consumes this [`Lazy<T, Eval>`](struct.Lazy.html) instance and extracts the evaluation result value.
@@ -254,51 +784,583 @@ impl<T, Eval> Lazy<T, Eval>
     pub fn unwrap(self) -> T {
         self.init_once();
 
-        self.value_cell.replace(None).expect(EXPECT_VALUE_CELL_INITIALIZED)
+        self.value_cell.into_inner().expect(EXPECT_VALUE_CELL_INITIALIZED)
     }
 
-    //
-    // Service
-    //
-
-    fn as_ref_impl(&self) -> &T {
-        self.init_once();
+    /// Consumes this [`Lazy<T, Eval>`](struct.Lazy.html) instance and extracts the evaluation result value,
+    /// if it has already been evaluated, otherwise returns `None`, dropping the evaluator.
+    /// 
+    /// This does **not** invoke evaluator function.
+    #[must_use]
+    pub fn into_inner(self) -> Option<T> {
+        self.value_cell.into_inner()
+    }
 
-        unsafe {
-            self.value_cell
-                .as_ptr()
-                .as_ref()
-                .expect(EXPECT_VALUE_CELL_PTR_NOT_NULL)
-                .as_ref()
-                .expect(EXPECT_VALUE_CELL_INITIALIZED)
+    /// Consumes this [`Lazy<T, Eval>`](struct.Lazy.html) instance and returns `Ok` with the evaluation result value,
+    /// if it has already been evaluated, or `Err` with the unused evaluator function otherwise.
+    /// 
+    /// Unlike [`into_inner()`](struct.Lazy.html#method.into_inner), this gives back the evaluator,
+    /// e.g. to be invoked later or to construct another `Lazy` with. The evaluator in `Err` is `None`
+    /// if this instance is unarmed, i.e. holds neither a value nor an evaluator,
+    /// e.g. after a call to [`take_evaluator()`](struct.Lazy.html#method.take_evaluator).
+    /// ```
+    /// use sloth::Lazy;
+    /// 
+    /// let lazy_value = Lazy::new(|| 42);
+    /// 
+    /// let evaluator = lazy_value.into_result().unwrap_err().unwrap();
+    /// 
+    /// assert_eq!(evaluator(), 42);
+    /// ```
+    /// 
+    /// This does **not** invoke evaluator function.
+    pub fn into_result(self) -> Result<T, Option<Eval>> {
+        match self.value_cell.into_inner() {
+            Some(value) => Ok(value),
+            None        => Err(self.evaluator_cell.into_inner())
         }
     }
 
-    fn as_mut_impl(&mut self) -> &mut T {
-        self.init_once();
+    /// Consumes this [`Lazy<T, Eval>`](struct.Lazy.html) instance and returns an iterator,
+    /// which yields the evaluation result exactly once.
+    ///
+    /// This will invoke evaluator function if none of the methods
+    /// or `*` deref operator were previously used.
+    pub fn into_iter_once(self) -> Once<T> {
+        iter::once(self.unwrap())
+    }
 
-        unsafe {
-            self.value_cell
-                .as_ptr()
-                .as_mut()
-                .expect(EXPECT_VALUE_CELL_PTR_NOT_NULL)
-                .as_mut()
-                .expect(EXPECT_VALUE_CELL_INITIALIZED)
-        }
+    /// Consumes this [`Lazy<T, Eval>`](struct.Lazy.html) instance and returns a [`Frozen<U>`](struct.Frozen.html)
+    /// with the result of applying `mapping` to the evaluation result value.
+    ///
+    /// This will invoke evaluator function if none of the methods
+    /// or `*` deref operator were previously used. Unlike other mapping methods,
+    /// `mapping` is invoked immediately.
+    pub fn map_freeze<U, F>(self, mapping: F) -> Frozen<U>
+        where F: FnOnce(T) -> U
+    {
+        Frozen::new(mapping(self.unwrap()))
     }
 
-    fn init_once(&self) {
-        if self.value_cell.borrow().is_none() {
-            *self.value_cell.borrow_mut() = Some(self.evaluate());
+    /// Removes and returns the evaluator function, if it has not been invoked yet.
+    ///
+    /// Returns `None` if the value has already been evaluated.
+    ///
+    /// This does **not** invoke evaluator function. After the evaluator has been taken,
+    /// this instance is left *unarmed*: it holds neither a value nor an evaluator,
+    /// so dereferencing it or calling any of the methods, which require the value,
+    /// will panic, until a new evaluator is supplied via [`arm()`](struct.Lazy.html#method.arm).
+    pub fn take_evaluator(&mut self) -> Option<Eval> {
+        if self.value_cell.get_mut().is_some() {
+            return None;
         }
+
+        self.evaluator_cell.get_mut().take()
     }
 
-    fn evaluate(&self) -> T {
-        let evaluator = self.evaluator_cell
-            .take()
-            .expect(EXPECT_EVALUATOR_STILL_PRESENT);
+    /// Supplies an evaluator function to an unarmed instance, i.e. one, which holds
+    /// neither a value nor an evaluator after a call to [`take_evaluator()`](struct.Lazy.html#method.take_evaluator).
+    ///
+    /// Returns `Err(evaluator)` without modifying this instance if it has already
+    /// been evaluated or still holds an evaluator.
+    ///
+    /// This does **not** invoke either the new or the existing evaluator function.
+    pub fn arm(&mut self, evaluator: Eval) -> Result<(), Eval> {
+        if self.value_cell.get_mut().is_some() {
+            return Err(evaluator);
+        }
+
+        let evaluator_option = self.evaluator_cell.get_mut();
+        if evaluator_option.is_some() {
+            return Err(evaluator);
+        }
+
+        *evaluator_option = Some(evaluator);
+
+        Ok(())
+    }
+
+    /// Drops the cached value, if any, and replaces the evaluator with `evaluator`,
+    /// so that the value is evaluated again by `evaluator` on next access.
+    /// 
+    /// Unlike [`arm()`](struct.Lazy.html#method.arm), this works regardless of the state of this instance;
+    /// an evaluator, which has not been invoked yet, is dropped.
+    /// 
+    /// This does **not** invoke either the new or the existing evaluator function.
+    pub fn reset(&mut self, evaluator: Eval) {
+        self.value_cell.take();
+        *self.evaluator_cell.get_mut() = Some(evaluator);
+    }
+
+    /// Immutably borrows the evaluation result, also returning the time it took
+    /// to evaluate, if the evaluation was performed during this call.
+    /// 
+    /// This will invoke evaluator function if none of the methods
+    /// or `*` deref operator were previously used, in which case the returned duration
+    /// is `Some`. If the value has already been cached, the returned duration is `None`.
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn access_timed(&self) -> (&T, Option<Duration>) {
+        let evaluation_duration = if self.is_initialized() {
+            None
+        } else {
+            let evaluation_start = Instant::now();
+
+            self.init_once();
+
+            Some(evaluation_start.elapsed())
+        };
+
+        (self.as_ref_impl(), evaluation_duration)
+    }
+
+    /// Applies `mapping` to a reference to the evaluation result, also returning
+    /// whether the evaluation was performed during this call.
+    /// 
+    /// This will invoke evaluator function if none of the methods
+    /// or `*` deref operator were previously used, in which case the returned flag is `true`.
+    pub fn map_or_force<U, F>(&self, mapping: F) -> (U, bool)
+        where F: FnOnce(&T) -> U
+    {
+        let is_evaluated_now = !self.is_initialized();
+
+        (mapping(self.as_ref_impl()), is_evaluated_now)
+    }
+
+    /// Returns a new lazy value, which, when accessed, evaluates this instance if needed
+    /// and obtains its own value by applying `projection` to a reference to this instance's value.
+    /// 
+    /// The returned instance borrows this one and therefore cannot outlive it.
+    /// Neither this instance's evaluator nor `projection` will be invoked
+    /// until the returned instance is dereferenced or one of its methods is invoked.
+    pub fn project_ref<'a, U, F>(&'a self, projection: F) -> Lazy<U, impl FnOnce() -> U + 'a>
+        where F: FnOnce(&'a T) -> U + 'a
+    {
+        Lazy::new(move || projection(self.as_ref_impl()))
+    }
+
+    /// Applies a fallible `projection` to a reference to the evaluation result,
+    /// returning either the projected reference or the projection's error.
+    /// 
+    /// This is useful when the projection may fail, e.g. when looking up a key in an evaluated map.
+    /// 
+    /// This will invoke evaluator function if none of the methods
+    /// or `*` deref operator were previously used.
+    pub fn try_project_ref<U, E, F>(&self, projection: F) -> Result<&U, E>
+        where F: FnOnce(&T) -> Result<&U, E>
+    {
+        projection(self.as_ref_impl())
+    }
+
+    /// Applies `projection` to a reference to the evaluation result
+    /// and returns an owned copy of the projected reference, e.g. a `String` from a `&str` field.
+    /// 
+    /// This will invoke evaluator function if none of the methods
+    /// or `*` deref operator were previously used.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn project_to_owned<U, F>(&self, projection: F) -> U::Owned
+        where U: ToOwned + ?Sized,
+              F: FnOnce(&T) -> &U
+    {
+        projection(self.as_ref_impl()).to_owned()
+    }
+
+    /// Consumes this instance and returns a new lazy value, which, when accessed,
+    /// evaluates this instance if needed and transforms its value via `mapping`.
+    /// ```
+    /// use sloth::Lazy;
+    /// 
+    /// let lazy_port = Lazy::new(|| "localhost:8080".to_string())
+    ///     .map(|address| address.rsplit(':').next().unwrap().parse::<u16>().unwrap());
+    /// 
+    /// assert_eq!(*lazy_port, 8080);
+    /// ```
+    /// 
+    /// Neither this instance's evaluator nor `mapping` will be invoked
+    /// until the returned instance is dereferenced or one of its methods is invoked.
+    pub fn map<U, F>(self, mapping: F) -> Lazy<U, impl FnOnce() -> U>
+        where F: FnOnce(T) -> U
+    {
+        Lazy::new(move || mapping(self.unwrap()))
+    }
+
+    /// Consumes this instance and returns a new lazy value, which, when accessed,
+    /// evaluates this instance if needed, passes the result to `f` and evaluates the lazy value it returns.
+    /// 
+    /// This allows chaining dependent lazy computations, each of which only runs if the final value is needed.
+    /// ```
+    /// use sloth::Lazy;
+    /// 
+    /// let lazy_path = Lazy::new(|| String::from("config.toml"));
+    /// let lazy_extension = lazy_path.and_then(|path| Lazy::new(move || path.rsplit('.').next().unwrap().to_string()));
+    /// 
+    /// assert_eq!(*lazy_extension, "toml");
+    /// ```
+    /// 
+    /// Neither this instance's evaluator, `f` nor the evaluator of the lazy value returned by it
+    /// will be invoked until the returned instance is dereferenced or one of its methods is invoked.
+    pub fn and_then<U, F, UEval>(self, f: F) -> Lazy<U, impl FnOnce() -> U>
+        where F:     FnOnce(T) -> Lazy<U, UEval>,
+              UEval: FnOnce() -> U
+    {
+        Lazy::new(move || f(self.unwrap()).unwrap())
+    }
+
+    /// Consumes this instance and `other`, returning a new lazy value, which, when accessed,
+    /// evaluates both instances if needed and combines their values via `reduce`.
+    /// 
+    /// Neither of the evaluators nor `reduce` will be invoked
+    /// until the returned instance is dereferenced or one of its methods is invoked.
+    pub fn merge<OtherEval, F>(self, other: Lazy<T, OtherEval>, reduce: F) -> Lazy<T, impl FnOnce() -> T>
+        where OtherEval: FnOnce() -> T,
+              F:         FnOnce(T, T) -> T
+    {
+        Lazy::new(move || reduce(self.unwrap(), other.unwrap()))
+    }
+
+    /// Consumes `lazies`, returning a new lazy value, which, when accessed, evaluates
+    /// each of them in order, if needed, and folds their values into `init` via `reduce`.
+    /// 
+    /// This generalizes [`merge()`](struct.Lazy.html#method.merge) to any number of instances.
+    /// ```
+    /// use sloth::Lazy;
+    /// 
+    /// let lazy_parts = vec![
+    ///     Lazy::new_boxed(|| 1),
+    ///     Lazy::new_boxed(|| 2),
+    ///     Lazy::new_boxed(|| 3)
+    /// ];
+    /// 
+    /// let lazy_sum = Lazy::combine_all(lazy_parts, 0, |sum, part| sum + part);
+    /// 
+    /// assert_eq!(*lazy_sum, 6);
+    /// ```
+    /// 
+    /// None of the evaluators nor `reduce` will be invoked
+    /// until the returned instance is dereferenced or one of its methods is invoked.
+    #[cfg(feature = "alloc")]
+    pub fn combine_all<F>(lazies: Vec<Self>, init: T, reduce: F) -> Lazy<T, impl FnOnce() -> T>
+        where F: Fn(T, T) -> T
+    {
+        Lazy::new(move || lazies.into_iter().map(Self::unwrap).fold(init, reduce))
+    }
+
+    /// Consumes this instance and passes it to `f`, returning its result.
+    /// 
+    /// This allows chaining combinators, which consume `Lazy` values,
+    /// and user functions in postfix style instead of nesting calls.
+    /// 
+    /// This does **not** invoke evaluator function by itself.
+    pub fn pipe<U, F>(self, f: F) -> U
+        where F: FnOnce(Self) -> U
+    {
+        f(self)
+    }
+
+    /// Invokes `f` with a reference to the evaluation result, if it has already been evaluated,
+    /// and returns this instance unchanged, allowing calls to be chained.
+    /// 
+    /// This is useful for side effects, such as logging, in pipelines of combinators,
+    /// which should not cause evaluation by themselves.
+    /// 
+    /// This does **not** invoke evaluator function.
+    #[must_use]
+    pub fn tap_if_evaluated<F>(self, f: F) -> Self
+        where F: FnOnce(&T)
+    {
+        if let Some(value) = self.peek_impl() {
+            f(value);
+        }
+
+        self
+    }
+
+    /// Applies `modification` to the evaluation result and returns a mutable reference to it,
+    /// allowing calls to be chained.
+    /// 
+    /// This will invoke evaluator function if none of the methods
+    /// or `*` deref operator were previously used.
+    pub fn apply<F>(&mut self, modification: F) -> &mut T
+        where F: FnOnce(&mut T)
+    {
+        let value = self.as_mut_impl();
+
+        modification(value);
+
+        value
+    }
+
+    /// Mutably borrows the evaluation result via a [`LazyGuard`](struct.LazyGuard.html),
+    /// which can be given a callback to invoke once the borrow ends.
+    /// 
+    /// This will invoke evaluator function if none of the methods
+    /// or `*` deref operator were previously used.
+    #[cfg(feature = "alloc")]
+    pub fn guard(&mut self) -> LazyGuard<'_, T, Eval> {
+        self.as_mut_impl();
+
+        LazyGuard::new(self)
+    }
+
+    /// Invokes `f` with a reference to the evaluation result and returns its result.
+    /// 
+    /// This is equivalent to `f(lazy.as_ref())`, but keeps evaluation and use of the value
+    /// in a single call, e.g. in a "force and use immediately" pattern.
+    /// 
+    /// This will invoke evaluator function if none of the methods
+    /// or `*` deref operator were previously used.
+    pub fn force_then<R, F>(&self, f: F) -> R
+        where F: FnOnce(&T) -> R
+    {
+        f(self.as_ref_impl())
+    }
+
+    /// Immutably borrows the evaluation result, if this instance has already been evaluated
+    /// or if `allow` permits evaluating it now, otherwise returns `None`.
+    /// 
+    /// `allow` is only invoked if this instance has not been evaluated yet,
+    /// which lets callers enforce a budget on evaluation cost.
+    /// 
+    /// This will invoke evaluator function if none of the methods
+    /// or `*` deref operator were previously used and `allow` returns `true`.
+    pub fn force_with_limit<F>(&self, allow: F) -> Option<&T>
+        where F: FnOnce() -> bool
+    {
+        if let Some(value) = self.peek_impl() {
+            return Some(value);
+        }
+
+        if allow() {
+            Some(self.as_ref_impl())
+        } else {
+            None
+        }
+    }
+
+    /// Mutably borrows the evaluation result, if this instance has already been evaluated,
+    /// otherwise returns `None`.
+    /// 
+    /// This does **not** invoke evaluator function.
+    pub fn try_get_mut(&mut self) -> Option<&mut T> {
+        self.value_cell.get_mut()
+    }
+
+    /// Moves the evaluation result out of this instance, if it has already been evaluated,
+    /// otherwise returns `None`.
+    /// 
+    /// After the value is taken, this instance is left unarmed, i.e. it holds
+    /// neither a value nor an evaluator, until re-armed via [`arm()`](struct.Lazy.html#method.arm)
+    /// or [`reset_reusing()`](struct.Lazy.html#method.reset_reusing).
+    /// An instance, which has not been evaluated yet, is left intact, including its evaluator,
+    /// so this method is safe to call in bulk, e.g. when draining values out of a collection of lazies.
+    /// 
+    /// This does **not** invoke evaluator function.
+    pub fn take_if_evaluated(&mut self) -> Option<T> {
+        self.value_cell.take()
+    }
+
+    /// Checks whether this instance has already been evaluated, i.e. whether it holds a value.
+    /// 
+    /// This does **not** invoke evaluator function.
+    #[must_use]
+    pub fn is_evaluated(&self) -> bool {
+        self.is_initialized()
+    }
+
+    /// Checks whether the evaluator function of this instance is being invoked right now.
+    /// 
+    /// This can only be `true` when called from the evaluator itself, e.g. via a captured reference
+    /// to this instance. Accessing the value in that case would require the value to be evaluated
+    /// before its evaluator returns, so it panics with a message about reentrant evaluation.
+    /// 
+    /// This does **not** invoke evaluator function.
+    #[must_use]
+    pub fn is_evaluating(&self) -> bool {
+        self.is_evaluating_cell.get()
+    }
+
+    /// Immutably borrows the evaluation result, if this instance has already been evaluated,
+    /// otherwise returns `None`.
+    /// 
+    /// This does **not** invoke evaluator function.
+    #[must_use]
+    pub fn peek(&self) -> Option<&T> {
+        self.peek_impl()
+    }
+
+    /// Returns the current [`LazyState`](enum.LazyState.html) of this instance.
+    /// 
+    /// This does **not** invoke evaluator function.
+    #[must_use]
+    pub fn state(&self) -> LazyState {
+        if self.is_evaluating() {
+            LazyState::Evaluating
+        } else if self.is_initialized() {
+            LazyState::Evaluated
+        } else if self.is_armed() {
+            LazyState::Unevaluated
+        } else {
+            LazyState::Unarmed
+        }
+    }
+
+    //
+    // Service
+    //
+
+    pub(crate) fn new_evaluated(value: T) -> Self {
+        Self{
+            value_cell:         ValueCell::with_value(value),
+            evaluator_cell:     Cell::new(None),
+            evaluator_template: None,
+            is_evaluating_cell: Cell::new(false)
+        }
+    }
+
+    fn as_ref_impl(&self) -> &T {
+        self.value_cell.get_or_init(|| self.evaluate())
+    }
+
+    fn peek_impl(&self) -> Option<&T> {
+        self.value_cell.get()
+    }
+
+    fn as_mut_impl(&mut self) -> &mut T {
+        self.init_once();
+
+        self.value_cell
+            .get_mut()
+            .expect(EXPECT_VALUE_CELL_INITIALIZED)
+    }
+
+    fn is_initialized(&self) -> bool {
+        self.value_cell.is_initialized()
+    }
+
+    fn is_armed(&self) -> bool {
+        let evaluator_option = self.evaluator_cell.take();
+        let is_armed = evaluator_option.is_some();
+
+        self.evaluator_cell.set(evaluator_option);
+
+        is_armed
+    }
+
+    fn init_once(&self) {
+        self.as_ref_impl();
+    }
+
+    fn evaluate(&self) -> T {
+        assert!(!self.is_evaluating(), "{}", REENTRANT_EVALUATION_MESSAGE);
+
+        let evaluator = self.evaluator_cell
+            .take()
+            .expect(EXPECT_ARMED_ON_ACCESS);
+
+        let evaluation_guard = EvaluationGuard::new(self);
+
+        let value = evaluator();
+
+        evaluation_guard.finish();
+
+        value
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Lazy<T, Box<dyn FnOnce() -> T>> {
+    /// Constructs a lazy `T` instance, like [`new()`](struct.Lazy.html#method.new) does,
+    /// but boxes `evaluator`, so that the type of the returned instance is simply [`BoxedLazy<T>`](type.BoxedLazy.html).
+    /// 
+    /// This allows storing lazy values with different evaluators in the same collection
+    /// or in struct fields without spelling out evaluator types, at the cost of an allocation
+    /// and dynamic dispatch on evaluation.
+    /// 
+    /// `evaluator` will be invoked only the first time this instance
+    /// is dereferenced or one of its methods is invoked.
+    pub fn new_boxed<Eval>(evaluator: Eval) -> Self
+        where Eval: FnOnce() -> T + 'static
+    {
+        Lazy::new(Box::new(evaluator))
+    }
+}
+
+impl<T> Lazy<T, fn() -> T> {
+    /// Constructs a lazy `T` instance, whose value, if needed, will later be
+    /// obtained by passing `reader` to `parse` and cached.
+    /// 
+    /// Both reading and parsing are deferred: `reader` is consumed by `parse` exactly once,
+    /// the first time the returned instance is dereferenced or one of its methods is invoked,
+    /// and is dropped unused if the value is never accessed.
+    #[cfg(feature = "std")]
+    pub fn from_reader<R, F>(reader: R, parse: F) -> Lazy<T, impl FnOnce() -> T>
+        where R: io::Read,
+              F: FnOnce(R) -> T
+    {
+        Lazy::new(move || parse(reader))
+    }
+
+    /// Constructs an already evaluated lazy `T` instance, holding `value`.
+    /// 
+    /// This is useful in generic code, where some branches construct a `Lazy` from an evaluator,
+    /// while others already have the value. The returned instance has no evaluator
+    /// and will never invoke one.
+    pub fn from_value(value: T) -> Self {
+        Self::new_evaluated(value)
+    }
+
+    /// Constructs a lazy `T` instance, wrapped in a [`SeededLazy<S, T, Gen>`](struct.SeededLazy.html),
+    /// whose value, if needed, will later be obtained by passing `seed` to `generator` and cached.
+    /// 
+    /// Unlike a seed captured by an evaluator, the pending seed can be inspected
+    /// via [`seed_ref()`](struct.SeededLazy.html#method.seed_ref) before the value is generated.
+    /// 
+    /// `generator` will be invoked only the first time the returned instance is dereferenced.
+    pub fn new_seeded<S, Gen>(seed: S, generator: Gen) -> SeededLazy<S, T, Gen>
+        where Gen: FnOnce(S) -> T
+    {
+        SeededLazy::new(seed, generator)
+    }
+
+    /// Constructs a lazy `T` instance, whose value, if needed, will later be
+    /// obtained from `value_producer` function and cached.
+    /// 
+    /// This is equivalent to [`new()`](struct.Lazy.html#method.new), but is a `const fn`,
+    /// so it can be used in `const` contexts, such as `const` initializers of `thread_local!` statics.
+    /// Since `Lazy` is not `Sync`, it cannot be stored in a plain `static`,
+    /// use [`SyncLazy<T, Eval>`](struct.SyncLazy.html) for that.
+    /// ```
+    /// use sloth::Lazy;
+    ///
+    /// fn load_greeting() -> String {
+    ///     "hello".to_string()
+    /// }
+    ///
+    /// thread_local! {
+    ///     static GREETING: Lazy<String, fn() -> String> = const { Lazy::with_evaluator(load_greeting) };
+    /// }
+    ///
+    /// GREETING.with(|greeting| assert_eq!(greeting.as_str(), "hello"));
+    /// ```
+    pub const fn with_evaluator(value_producer: fn() -> T) -> Self {
+        Self{
+            value_cell:         ValueCell::new(),
+            evaluator_cell:     Cell::new(Some(value_producer)),
+            evaluator_template: None,
+            is_evaluating_cell: Cell::new(false)
+        }
+    }
+}
 
-        evaluator()
+#[cfg(any(test, feature = "bench"))]
+impl<T> Lazy<T, fn() -> T> {
+    /// Constructs an already evaluated lazy `T` instance, holding `value`.
+    /// 
+    /// This is intended for benchmarks, comparing access to a warm, pre-evaluated
+    /// instance with access to a cold one, and is only available with the `bench` feature.
+    pub fn preinitialized(value: T) -> Self {
+        Self::from_value(value)
     }
 }
 
@@ -312,8 +1374,569 @@ impl<T, Eval> Lazy<T, Eval>
     /// or `*` deref operator were previously used.
     #[must_use]
     pub fn value(&self) -> T {
-        self.init_once();
+        *self.as_ref_impl()
+    }
+}
+
+impl<T, Eval> Lazy<T, Eval>
+    where Eval: FnOnce() -> T + Clone
+{
+    /// Constructs a lazy `T` instance, like [`new()`](struct.Lazy.html#method.new) does,
+    /// but also keeps a copy of `evaluator` for re-arming this instance
+    /// via [`reset_reusing()`](struct.Lazy.html#method.reset_reusing).
+    /// 
+    /// The copy is also used to re-arm this instance automatically if `evaluator` panics,
+    /// so that the evaluation can be retried on next access, e.g. after the panic is caught
+    /// with [`catch_unwind()`](https://doc.rust-lang.org/std/panic/fn.catch_unwind.html).
+    /// 
+    /// `evaluator` will be invoked only the first time this instance
+    /// is dereferenced or one of its methods is invoked.
+    pub fn new_reusable(evaluator: Eval) -> Self {
+        Self{
+            value_cell:         ValueCell::new(),
+            evaluator_cell:     Cell::new(Some(evaluator.clone())),
+            evaluator_template: Some(EvaluatorTemplate::new(evaluator)),
+            is_evaluating_cell: Cell::new(false)
+        }
+    }
+
+    /// Drops the cached value, if any, so that the value is evaluated again on next access,
+    /// using a copy of the evaluator originally passed to [`new_reusable()`](struct.Lazy.html#method.new_reusable).
+    /// 
+    /// If this instance has not been evaluated yet, the stored evaluator is kept.
+    /// 
+    /// This does **not** invoke evaluator function.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if this instance holds no evaluator and was not constructed with
+    /// [`new_reusable()`](struct.Lazy.html#method.new_reusable).
+    pub fn reset_reusing(&mut self) {
+        self.value_cell.take();
+
+        let evaluator_option = self.evaluator_cell.get_mut();
+        if evaluator_option.is_none() {
+            *evaluator_option = Some(
+                self.evaluator_template
+                    .as_ref()
+                    .map(EvaluatorTemplate::instantiate)
+                    .expect(EXPECT_EVALUATOR_TEMPLATE_PRESENT)
+            );
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, Eval> Lazy<T, Eval>
+    where Eval: FnOnce() -> T + UnwindSafe
+{
+    /// Consumes this [`Lazy<T, Eval>`](struct.Lazy.html) instance and returns a new lazy value,
+    /// which caches `Ok` with the evaluation result or `Err` with the panic message,
+    /// if the evaluator panics.
+    /// 
+    /// Neither this instance's evaluator nor the panic handling logic will run until
+    /// the returned instance is dereferenced or one of its methods is invoked.
+    /// A panic is caught only once, subsequent accesses return the cached `Err`.
+    /// 
+    /// Note that the panic hook still runs for the caught panic and that
+    /// panics can only be caught if the crate is built with `panic = "unwind"`.
+    pub fn catch(self) -> Lazy<Result<T, String>, impl FnOnce() -> Result<T, String>> {
+        // A panicking evaluator can only leave this instance, which is discarded anyway, in an inconsistent state
+        let source = AssertUnwindSafe(self);
+
+        Lazy::new(move || {
+            panic::catch_unwind(move || source.0.unwrap())
+                .map_err(panic_message)
+        })
+    }
+}
 
-        self.value_cell.borrow().expect(EXPECT_VALUE_CELL_INITIALIZED)
+#[cfg(feature = "alloc")]
+impl<Eval> Lazy<String, Eval>
+    where Eval: FnOnce() -> String
+{
+    /// Checks that the evaluation result is an ASCII case-insensitive match for `other`.
+    /// 
+    /// This will invoke evaluator function if none of the methods
+    /// or `*` deref operator were previously used.
+    #[must_use]
+    pub fn eq_ignore_ascii_case(&self, other: &str) -> bool {
+        self.as_ref_impl().eq_ignore_ascii_case(other)
+    }
+
+    /// Borrows the evaluated string as a string slice.
+    /// 
+    /// This will invoke evaluator function if none of the methods
+    /// or `*` deref operator were previously used.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        self.as_ref_impl().as_str()
+    }
+}
+
+impl<'a, Eval> Lazy<&'a str, Eval>
+    where Eval: FnOnce() -> &'a str
+{
+    /// Returns the evaluated string slice.
+    /// 
+    /// This will invoke evaluator function if none of the methods
+    /// or `*` deref operator were previously used.
+    #[must_use]
+    pub fn as_str(&self) -> &'a str {
+        self.as_ref_impl()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<Eval> Lazy<Vec<String>, Eval>
+    where Eval: FnOnce() -> Vec<String>
+{
+    /// Returns a vector of string slices, borrowing the strings in the evaluated vector,
+    /// e.g. to be passed as `&[&str]`.
+    /// 
+    /// This will invoke evaluator function if none of the methods
+    /// or `*` deref operator were previously used.
+    #[must_use]
+    pub fn as_str_slices(&self) -> Vec<&str> {
+        self.as_ref_impl()
+            .iter()
+            .map(String::as_str)
+            .collect()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, Eval> Lazy<Vec<T>, Eval>
+    where Eval: FnOnce() -> Vec<T>
+{
+    /// Removes all elements matching `predicate` from the evaluated vector
+    /// and returns them, preserving their relative order.
+    /// 
+    /// Elements, for which `predicate` returns `false`, remain cached in this instance.
+    /// 
+    /// This will invoke evaluator function if none of the methods
+    /// or `*` deref operator were previously used.
+    pub fn extract_if<F>(&mut self, mut predicate: F) -> Vec<T>
+        where F: FnMut(&T) -> bool
+    {
+        let values = self.as_mut_impl();
+
+        let (extracted_values, retained_values) = mem::take(values)
+            .into_iter()
+            .partition(|value| predicate(value));
+
+        *values = retained_values;
+
+        extracted_values
+    }
+
+    /// Consumes this instance and returns a new lazy vector, which, when accessed,
+    /// evaluates this instance if needed and maps each element together with its index via `mapping`.
+    /// 
+    /// Neither this instance's evaluator nor `mapping` will be invoked
+    /// until the returned instance is dereferenced or one of its methods is invoked.
+    pub fn map_indexed<U, F>(self, mapping: F) -> Lazy<Vec<U>, impl FnOnce() -> Vec<U>>
+        where F: Fn(usize, T) -> U
+    {
+        Lazy::new(move || {
+            self.unwrap()
+                .into_iter()
+                .enumerate()
+                .map(|(index, value)| mapping(index, value))
+                .collect()
+        })
+    }
+
+    /// Consumes this instance and returns a new lazy `Result`, which, when accessed,
+    /// evaluates this instance if needed and maps each element via fallible `mapping`.
+    /// 
+    /// Mapping stops at the first `Err` returned by `mapping`, which is then cached,
+    /// otherwise `Ok` with all mapped elements is cached.
+    /// 
+    /// Neither this instance's evaluator nor `mapping` will be invoked
+    /// until the returned instance is dereferenced or one of its methods is invoked.
+    #[allow(clippy::type_complexity)]
+    pub fn try_map_each<U, E, F>(self, mapping: F) -> Lazy<Result<Vec<U>, E>, impl FnOnce() -> Result<Vec<U>, E>>
+        where F: FnMut(T) -> Result<U, E>
+    {
+        Lazy::new(move || {
+            self.unwrap()
+                .into_iter()
+                .map(mapping)
+                .collect()
+        })
+    }
+
+    /// Consumes this instance and moves all elements of the evaluated vector into `target`,
+    /// preserving their order.
+    /// 
+    /// This will invoke evaluator function if none of the methods
+    /// or `*` deref operator were previously used.
+    pub fn extend_into<C>(self, target: &mut C)
+        where C: Extend<T>
+    {
+        target.extend(self.unwrap());
+    }
+
+    /// Returns the number of elements in the evaluated vector.
+    /// 
+    /// Unlike [`count_if_evaluated()`](struct.Lazy.html#method.count_if_evaluated),
+    /// this will invoke evaluator function if none of the methods
+    /// or `*` deref operator were previously used.
+    #[must_use]
+    pub fn count(&self) -> usize {
+        self.as_ref_impl().len()
+    }
+
+    /// Returns the number of elements in the vector, if it has already been evaluated,
+    /// otherwise returns `None`.
+    /// 
+    /// Unlike [`count()`](struct.Lazy.html#method.count),
+    /// this does **not** invoke evaluator function.
+    #[must_use]
+    pub fn count_if_evaluated(&self) -> Option<usize> {
+        self.peek_impl().map(Vec::len)
+    }
+}
+
+impl<T, E, Eval> Lazy<Result<T, E>, Eval>
+    where Eval: FnOnce() -> Result<T, E>
+{
+    /// Immutably borrows the contents of the evaluated `Result`,
+    /// without consuming this instance.
+    /// 
+    /// This will invoke evaluator function if none of the methods
+    /// or `*` deref operator were previously used.
+    pub fn as_result(&self) -> Result<&T, &E> {
+        self.as_ref_impl().as_ref()
+    }
+
+    /// Immutably borrows the contents of the evaluated `Result`, like
+    /// [`as_result()`](struct.Lazy.html#method.as_result) does.
+    /// 
+    /// This will invoke evaluator function if none of the methods
+    /// or `*` deref operator were previously used.
+    pub fn try_as_ref(&self) -> Result<&T, &E> {
+        self.as_result()
+    }
+
+    /// Consumes this instance and returns the evaluated `Result` by value,
+    /// so that the error can be propagated with `?`.
+    /// 
+    /// This will invoke evaluator function if none of the methods
+    /// or `*` deref operator were previously used.
+    pub fn try_unwrap(self) -> Result<T, E> {
+        self.unwrap()
+    }
+}
+
+impl<T, E, Eval> Lazy<Result<T, E>, Eval>
+    where E:    Debug,
+          Eval: FnOnce() -> Result<T, E>
+{
+    /// Consumes this instance and returns a new lazy value, which, when accessed,
+    /// evaluates this instance if needed and caches the `Ok` value of the result.
+    /// 
+    /// # Panics
+    /// 
+    /// Accessing the returned instance panics with the `Debug` representation of the error
+    /// if the evaluated result is `Err`.
+    /// 
+    /// This instance's evaluator will not be invoked
+    /// until the returned instance is dereferenced or one of its methods is invoked.
+    pub fn unwrap_ok(self) -> Lazy<T, impl FnOnce() -> T> {
+        Lazy::new(move || self.unwrap().expect(EXPECT_RESULT_OK))
+    }
+}
+
+impl<T, E, Eval> Lazy<Result<Result<T, E>, E>, Eval>
+    where Eval: FnOnce() -> Result<Result<T, E>, E>
+{
+    /// Consumes this instance and returns a new lazy value, which, when accessed,
+    /// evaluates this instance if needed and collapses the nested result into a single one,
+    /// being `Err` if either the outer or the inner result is `Err`.
+    /// 
+    /// This instance's evaluator will not be invoked
+    /// until the returned instance is dereferenced or one of its methods is invoked.
+    pub fn flatten_result(self) -> Lazy<Result<T, E>, impl FnOnce() -> Result<T, E>> {
+        Lazy::new(move || self.unwrap().and_then(|inner_result| inner_result))
+    }
+}
+
+impl<T, Eval> Lazy<T, Eval>
+    where T:    PartialEq,
+          Eval: FnOnce() -> T
+{
+    /// Checks whether the evaluation result is equal to `value`.
+    /// 
+    /// This will invoke evaluator function if none of the methods
+    /// or `*` deref operator were previously used.
+    #[must_use]
+    pub fn contains(&self, value: &T) -> bool {
+        self.as_ref_impl() == value
+    }
+}
+
+impl<T, Eval> Lazy<T, Eval>
+    where T:    Default,
+          Eval: FnOnce() -> T
+{
+    /// Consumes this [`Lazy<T, Eval>`](struct.Lazy.html) instance and extracts the evaluation result value,
+    /// if it has already been evaluated, otherwise returns `T::default()`.
+    /// 
+    /// Unlike [`unwrap()`](struct.Lazy.html#method.unwrap), this is safe to use in teardown paths,
+    /// where running a possibly expensive or panicking evaluator is undesirable.
+    /// 
+    /// This does **not** invoke evaluator function, which is dropped unused
+    /// if the value has not been evaluated.
+    #[must_use]
+    pub fn into_inner_or_default(self) -> T {
+        self.into_inner().unwrap_or_default()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<A, B, Eval> Lazy<(A, B), Eval>
+    where Eval: FnOnce() -> (A, B)
+{
+    /// Consumes this [`Lazy<(A, B), Eval>`](struct.Lazy.html) instance and splits it
+    /// into two lazy values, one for each component of the evaluated pair.
+    /// 
+    /// Both returned instances share this instance's evaluation: accessing either of them
+    /// invokes this instance's evaluator, if needed, and the evaluator is never invoked more than once.
+    /// Nothing is evaluated until one of the returned instances is dereferenced
+    /// or one of its methods is invoked.
+    pub fn unzip(self) -> (Lazy<A, impl FnOnce() -> A>, Lazy<B, impl FnOnce() -> B>) {
+        let first_source_rc = Rc::new(Lazy::new(move || {
+            let (first, second) = self.unwrap();
+
+            (Cell::new(Some(first)), Cell::new(Some(second)))
+        }));
+        let second_source_rc = Rc::clone(&first_source_rc);
+
+        (
+            Lazy::new(move || first_source_rc.0.take().expect(EXPECT_UNZIPPED_COMPONENT_PRESENT)),
+            Lazy::new(move || second_source_rc.1.take().expect(EXPECT_UNZIPPED_COMPONENT_PRESENT))
+        )
+    }
+}
+
+impl<T, Eval> Lazy<Option<T>, Eval>
+    where Eval: FnOnce() -> Option<T>
+{
+    /// Consumes this instance and returns a new lazy value, which, when accessed,
+    /// evaluates this instance if needed and yields the contained value if it is `Some`,
+    /// or otherwise invokes `fallback` to obtain the value.
+    /// 
+    /// `fallback` is only invoked if this instance's value is `None`.
+    /// Neither this instance's evaluator nor `fallback` will be invoked
+    /// until the returned instance is dereferenced or one of its methods is invoked.
+    pub fn coalesce<F>(self, fallback: F) -> Lazy<T, impl FnOnce() -> T>
+        where F: FnOnce() -> T
+    {
+        Lazy::new(move || self.unwrap().unwrap_or_else(fallback))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<N, Eval> Lazy<Vec<N>, Eval>
+    where N:    Copy,
+          Eval: FnOnce() -> Vec<N>
+{
+    /// Returns the sum of all elements of the evaluated vector.
+    /// 
+    /// This will invoke evaluator function if none of the methods
+    /// or `*` deref operator were previously used.
+    #[must_use]
+    pub fn sum(&self) -> N
+        where N: Sum
+    {
+        self.as_ref_impl().iter().copied().sum()
+    }
+
+    /// Returns the product of all elements of the evaluated vector.
+    /// 
+    /// This will invoke evaluator function if none of the methods
+    /// or `*` deref operator were previously used.
+    #[must_use]
+    pub fn product(&self) -> N
+        where N: Product
+    {
+        self.as_ref_impl().iter().copied().product()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, E, Eval> Lazy<Vec<Result<T, E>>, Eval>
+    where Eval: FnOnce() -> Vec<Result<T, E>>
+{
+    /// Consumes this instance and returns a new lazy value, which, when accessed,
+    /// evaluates this instance if needed and collects the evaluated results into `Ok` with a vector
+    /// of values or the first encountered `Err`.
+    /// 
+    /// This instance's evaluator will not be invoked until the returned instance
+    /// is dereferenced or one of its methods is invoked.
+    #[allow(clippy::type_complexity)]
+    pub fn collapse(self) -> Lazy<Result<Vec<T>, E>, impl FnOnce() -> Result<Vec<T>, E>> {
+        Lazy::new(move || self.unwrap().into_iter().collect())
+    }
+}
+
+impl<T, Eval> Lazy<T, Eval>
+    where T:    Ord,
+          Eval: FnOnce() -> T
+{
+    /// Compares the evaluation results of this and `other` instances,
+    /// if both have already been evaluated, otherwise returns `None`.
+    /// 
+    /// This does **not** invoke evaluator function of either instance.
+    #[must_use]
+    pub fn cmp_if_evaluated(&self, other: &Self) -> Option<Ordering> {
+        match (self.peek_impl(), other.peek_impl()) {
+            (Some(value), Some(other_value)) => Some(value.cmp(other_value)),
+            _                                => None
+        }
+    }
+}
+
+impl<Eval> Lazy<f64, Eval>
+    where Eval: FnOnce() -> f64
+{
+    /// Checks whether the evaluation result differs from `other` by no more than `epsilon`.
+    /// 
+    /// Equal values, including equal infinities, are always considered approximately equal.
+    /// If either number is NaN, returns `false`.
+    /// 
+    /// This will invoke evaluator function if none of the methods
+    /// or `*` deref operator were previously used.
+    #[must_use]
+    pub fn approx_eq(&self, other: f64, epsilon: f64) -> bool {
+        let value = *self.as_ref_impl();
+
+        if value == other {
+            return true;
+        }
+
+        (value - other).abs() <= epsilon
+    }
+}
+
+impl<Eval> Lazy<f32, Eval>
+    where Eval: FnOnce() -> f32
+{
+    /// Checks whether the evaluation result differs from `other` by no more than `epsilon`.
+    /// 
+    /// Equal values, including equal infinities, are always considered approximately equal.
+    /// If either number is NaN, returns `false`.
+    /// 
+    /// This will invoke evaluator function if none of the methods
+    /// or `*` deref operator were previously used.
+    #[must_use]
+    pub fn approx_eq(&self, other: f32, epsilon: f32) -> bool {
+        let value = *self.as_ref_impl();
+
+        if value == other {
+            return true;
+        }
+
+        (value - other).abs() <= epsilon
+    }
+}
+
+impl<T, Eval> Lazy<T, Eval>
+    where T:    Display,
+          Eval: FnOnce() -> T
+{
+    /// Immutably borrows the evaluation result as a `&dyn Display` trait object.
+    /// 
+    /// This allows formatting lazy values of different types uniformly, e.g. by storing them in a single collection.
+    /// ```
+    /// use std::fmt::Display;
+    /// 
+    /// use sloth::Lazy;
+    /// 
+    /// let lazy_number = Lazy::new(|| 42);
+    /// let lazy_string = Lazy::new(|| "moo");
+    /// 
+    /// let displayables: Vec<&dyn Display> = vec![lazy_number.as_dyn_display(), lazy_string.as_dyn_display()];
+    /// 
+    /// assert_eq!(displayables.iter().map(|value| value.to_string()).collect::<Vec<_>>(), ["42", "moo"]);
+    /// ```
+    /// 
+    /// This will invoke evaluator function if none of the methods
+    /// or `*` deref operator were previously used.
+    #[must_use]
+    pub fn as_dyn_display(&self) -> &dyn Display {
+        self.as_ref_impl()
+    }
+}
+
+//
+// Service
+//
+
+#[cfg(feature = "std")]
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => payload.downcast_ref::<&str>()
+            .map_or_else(|| UNKNOWN_PANIC_MESSAGE.to_string(), |message| message.to_string())
+    }
+}
+
+#[derive(Clone)]
+struct EvaluatorTemplate<Eval> {
+    evaluator:       Eval,
+    clone_evaluator: fn(&Eval) -> Eval
+}
+
+impl<Eval> EvaluatorTemplate<Eval> {
+    fn new(evaluator: Eval) -> Self
+        where Eval: Clone
+    {
+        Self{
+            evaluator,
+            clone_evaluator: Eval::clone
+        }
+    }
+
+    fn instantiate(&self) -> Eval {
+        (self.clone_evaluator)(&self.evaluator)
+    }
+}
+
+// Marks lazy as being evaluated until finished or dropped while unwinding from a panicking evaluator,
+// in which case instances constructed with new_reusable() are also re-armed
+struct EvaluationGuard<'a, T, Eval>
+    where Eval: FnOnce() -> T
+{
+    lazy: &'a Lazy<T, Eval>
+}
+
+impl<'a, T, Eval> EvaluationGuard<'a, T, Eval>
+    where Eval: FnOnce() -> T
+{
+    fn new(lazy: &'a Lazy<T, Eval>) -> Self {
+        lazy.is_evaluating_cell.set(true);
+
+        Self{lazy}
+    }
+
+    fn finish(self) {
+        self.lazy.is_evaluating_cell.set(false);
+
+        mem::forget(self);
+    }
+}
+
+impl<T, Eval> Drop for EvaluationGuard<'_, T, Eval>
+    where Eval: FnOnce() -> T
+{
+    fn drop(&mut self) {
+        self.lazy.is_evaluating_cell.set(false);
+
+        if let Some(evaluator_template) = &self.lazy.evaluator_template {
+            self.lazy.evaluator_cell.set(Some(evaluator_template.instantiate()));
+        }
     }
 }
@@ -60,15 +60,71 @@
 //! 
 //! let output = String::from("a cow goes ") + lazy_value.unwrap();
 //! ```
+//! 
+//! # `no_std` support
+//! 
+//! The crate depends on the standard library via the default `std` feature.
+//! With default features disabled, it is `#![no_std]` and provides the core [`Lazy`](struct.Lazy.html) API,
+//! while the `alloc` feature additionally enables boxed evaluators and other APIs, which need heap allocation.
+//! 
+//! # `safe-only` feature
+//! 
+//! By default, [`Lazy`](struct.Lazy.html) hands out references to its evaluated value via a raw pointer
+//! into its `RefCell` storage. The `safe-only` feature switches it to `OnceCell` storage instead,
+//! which involves no `unsafe` code, e.g. for running under strict sanitizers. The public API
+//! is the same with and without this feature.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
 //
 // Modules
 //
 
+mod macros;
 mod lazy;
+mod value_cell;
+mod error;
+#[cfg(feature = "alloc")]
+mod shared;
+#[cfg(feature = "std")]
+mod sync;
+#[cfg(feature = "std")]
+mod cache;
+#[cfg(feature = "alloc")]
+mod fallback;
+mod frozen;
+mod seeded;
+#[cfg(feature = "alloc")]
+mod guard;
+#[cfg(feature = "std")]
+mod ttl;
+#[cfg(feature = "std")]
+mod clock;
+#[cfg(feature = "std")]
+mod batch;
+mod counting;
+#[cfg(feature = "std")]
+mod lru;
+mod cloning;
+mod recomputing;
+#[cfg(feature = "std")]
+mod keyed;
+#[cfg(feature = "alloc")]
+mod cancel;
+#[cfg(feature = "std")]
+mod timed;
+#[cfg(feature = "std")]
+mod memoized;
+#[cfg(feature = "arc-swap")]
+mod swappable;
+#[cfg(feature = "async")]
+mod async_lazy;
 
 // Unit tests
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 #[allow(deprecated)]
 mod tests;
 
@@ -76,4 +132,64 @@ mod tests;
 // Exports
 //
 
-pub use lazy::Lazy;
+pub use lazy::{
+    Lazy,
+    LazyState,
+    IntoIter
+};
+#[cfg(feature = "alloc")]
+pub use lazy::BoxedLazy;
+pub use error::ValidationError;
+#[cfg(feature = "alloc")]
+pub use shared::{
+    SharedLazy,
+    WeakSharedLazy
+};
+#[cfg(feature = "std")]
+pub use sync::{
+    SyncLazy,
+    new_shared_sync
+};
+#[cfg(feature = "std")]
+pub use cache::{
+    Cache,
+    cached
+};
+#[cfg(feature = "alloc")]
+pub use fallback::FallbackLazy;
+pub use frozen::Frozen;
+pub use seeded::SeededLazy;
+#[cfg(feature = "alloc")]
+pub use guard::LazyGuard;
+#[cfg(feature = "std")]
+pub use ttl::TtlLazy;
+#[cfg(feature = "std")]
+pub use clock::{
+    Clock,
+    SystemClock,
+    MockClock
+};
+#[cfg(feature = "std")]
+pub use batch::{
+    force_map_collect,
+    scoped_force_all
+};
+pub use counting::CountingLazy;
+#[cfg(feature = "std")]
+pub use lru::LruLazyPool;
+pub use cloning::CloningLazy;
+pub use recomputing::RecomputingLazy;
+#[cfg(feature = "std")]
+pub use keyed::KeyedLazy;
+#[cfg(feature = "alloc")]
+pub use cancel::CancelToken;
+#[cfg(feature = "std")]
+pub use timed::TimedLazy;
+#[cfg(feature = "std")]
+pub use memoized::Memoized;
+#[cfg(feature = "arc-swap")]
+pub use swappable::SwappableLazy;
+#[cfg(feature = "async")]
+pub use async_lazy::AsyncLazy;
+#[cfg(feature = "derive")]
+pub use sloth_derive::ForceAll;
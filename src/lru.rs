@@ -0,0 +1,256 @@
+use std::collections::{
+    BTreeMap,
+    HashMap
+};
+use std::hash::Hash;
+use std::rc::Rc;
+
+use crate::{
+    Lazy,
+    LazyState
+};
+
+//
+// Constants
+//
+
+const ASSERT_CAPACITY_NOT_ZERO: &str = "LruLazyPool capacity must not be zero";
+
+//
+// Types
+//
+
+type SharedEvaluator<'a, V> = Rc<dyn Fn() -> V + 'a>;
+type BoxedEvaluator<'a, V>  = Box<dyn FnOnce() -> V + 'a>;
+type EvictCallback<'a, V>   = Box<dyn Fn(&V) + 'a>;
+
+//
+// Interface
+//
+
+//
+// struct LruLazyPool<'a, K, V>
+//
+
+/// A collection of lazily evaluated values of type `V`, identified by keys of type `K`,
+/// which keeps at most a fixed number of evaluated values at a time.
+///
+/// Each value is evaluated the first time it is accessed via [`get()`](struct.LruLazyPool.html#method.get).
+/// If the number of evaluated values has reached the capacity of the pool, evaluating another value
+/// first drops the least recently accessed one, which will be evaluated again if its key is accessed later.
+/// ```
+/// use sloth::LruLazyPool;
+///
+/// let mut pool = LruLazyPool::new(1);
+///
+/// pool.insert("answer", || 42);
+/// pool.insert("square", || 12 * 12);
+///
+/// assert_eq!(pool.get(&"answer"), Some(&42));
+/// assert_eq!(pool.get(&"square"), Some(&144)); // "answer" is evicted here
+///
+/// assert!(!pool.is_evaluated(&"answer"));
+/// ```
+///
+/// A callback, set with [`on_evict()`](struct.LruLazyPool.html#method.on_evict), is invoked
+/// with each value right before it is dropped due to eviction, which allows releasing
+/// any resources associated with it.
+pub struct LruLazyPool<'a, K, V> {
+    capacity:       usize,
+    entries:        HashMap<K, LruEntry<'a, V>>,
+    evaluated_keys: BTreeMap<u64, K>,
+    next_access:    u64,
+    on_evict:       Option<EvictCallback<'a, V>>
+}
+
+//
+// Methods
+//
+
+impl<'a, K, V> LruLazyPool<'a, K, V>
+    where K: Eq + Hash + Clone,
+          V: 'a
+{
+    //
+    // Interface
+    //
+
+    /// Constructs an empty pool, which keeps at most `capacity` evaluated values at a time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "{}", ASSERT_CAPACITY_NOT_ZERO);
+
+        Self{
+            capacity,
+            entries:        HashMap::new(),
+            evaluated_keys: BTreeMap::new(),
+            next_access:    0,
+            on_evict:       None
+        }
+    }
+
+    /// Adds a lazily evaluated value for `key`, replacing the previous one, if any.
+    ///
+    /// `evaluator` may be invoked several times, if the value is evicted from the pool and accessed again.
+    ///
+    /// This does **not** invoke `evaluator`.
+    pub fn insert<Eval>(&mut self, key: K, evaluator: Eval)
+        where Eval: Fn() -> V + 'a
+    {
+        if let Some(replaced_entry) = self.entries.insert(key, LruEntry::new(Rc::new(evaluator))) {
+            self.forget_evaluated(&replaced_entry);
+        }
+    }
+
+    /// Immutably borrows the value for `key`, evaluating it if needed,
+    /// or returns `None` if there is no value for `key` in the pool.
+    ///
+    /// If the value has to be evaluated while the pool already holds as many evaluated values
+    /// as its capacity allows, the least recently accessed value is dropped first.
+    ///
+    /// If the evaluator panics, the value remains unevaluated and does not count towards the capacity,
+    /// so that the next access for `key` invokes the evaluator again.
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        let entry = self.entries.get(key)?;
+
+        if entry.lazy.is_evaluated() {
+            let last_access = entry.last_access;
+
+            self.evaluated_keys.remove(&last_access);
+        } else if self.evaluated_keys.len() >= self.capacity {
+            self.evict_least_recently_used();
+        }
+
+        let access = self.next_access;
+        self.next_access += 1;
+
+        let entry = self.entries.get_mut(key)?;
+
+        entry.force();
+        entry.last_access = access;
+
+        self.evaluated_keys.insert(access, key.clone());
+
+        self.entries
+            .get(key)
+            .map(|entry| entry.lazy.force())
+    }
+
+    /// Sets `callback` to be invoked with each value evicted from the pool to make room
+    /// for another one, replacing a previously set callback, if any.
+    ///
+    /// Values dropped by [`insert()`](struct.LruLazyPool.html#method.insert) replacing them
+    /// or by dropping the pool itself are **not** passed to `callback`.
+    /// ```
+    /// use std::cell::RefCell;
+    ///
+    /// use sloth::LruLazyPool;
+    ///
+    /// let evicted_values = RefCell::new(Vec::new());
+    /// let mut pool = LruLazyPool::new(1);
+    ///
+    /// pool.on_evict(|value| evicted_values.borrow_mut().push(*value));
+    /// pool.insert("answer", || 42);
+    /// pool.insert("square", || 12 * 12);
+    ///
+    /// pool.get(&"answer");
+    /// pool.get(&"square"); // "answer" is evicted here
+    ///
+    /// assert_eq!(*evicted_values.borrow(), [42]);
+    /// ```
+    pub fn on_evict<F>(&mut self, callback: F)
+        where F: Fn(&V) + 'a
+    {
+        self.on_evict = Some(Box::new(callback));
+    }
+
+    /// Checks whether the value for `key` is currently evaluated and held by the pool.
+    ///
+    /// This does **not** invoke evaluator function.
+    #[must_use]
+    pub fn is_evaluated(&self, key: &K) -> bool {
+        self.entries
+            .get(key)
+            .is_some_and(|entry| entry.lazy.is_evaluated())
+    }
+
+    /// Returns the maximum number of evaluated values held by the pool at a time.
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    //
+    // Service
+    //
+
+    fn forget_evaluated(&mut self, entry: &LruEntry<'a, V>) {
+        if entry.lazy.is_evaluated() {
+            self.evaluated_keys.remove(&entry.last_access);
+        }
+    }
+
+    fn evict_least_recently_used(&mut self) {
+        if let Some((_, key)) = self.evaluated_keys.pop_first() {
+            if let Some(entry) = self.entries.get_mut(&key) {
+                let evicted_value = entry.reset();
+
+                if let (Some(value), Some(on_evict)) = (evicted_value, &self.on_evict) {
+                    on_evict(&value);
+                }
+            }
+        }
+    }
+}
+
+//
+// Service
+//
+
+//
+// struct LruEntry<'a, V>
+//
+
+struct LruEntry<'a, V> {
+    lazy:        Lazy<V, BoxedEvaluator<'a, V>>,
+    evaluator:   SharedEvaluator<'a, V>,
+    last_access: u64
+}
+
+impl<'a, V> LruEntry<'a, V>
+    where V: 'a
+{
+    fn new(evaluator: SharedEvaluator<'a, V>) -> Self {
+        Self{
+            lazy:        Lazy::new(Self::boxed_evaluator(&evaluator)),
+            evaluator,
+            last_access: 0
+        }
+    }
+
+    fn force(&mut self) {
+        // A panicking evaluator leaves the lazy unarmed, so it is re-armed to be retried
+        if self.lazy.state() == LazyState::Unarmed {
+            self.reset();
+        }
+
+        self.lazy.force();
+    }
+
+    fn reset(&mut self) -> Option<V> {
+        let value = self.lazy.take_if_evaluated();
+
+        self.lazy = Lazy::new(Self::boxed_evaluator(&self.evaluator));
+
+        value
+    }
+
+    fn boxed_evaluator(evaluator: &SharedEvaluator<'a, V>) -> BoxedEvaluator<'a, V> {
+        let evaluator = Rc::clone(evaluator);
+
+        Box::new(move || evaluator())
+    }
+}
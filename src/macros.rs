@@ -0,0 +1,52 @@
+//
+// Macros
+//
+
+/// Evaluates two [`Lazy`](struct.Lazy.html) values, if needed, and mutably borrows both of them at once.
+///
+/// Both arguments must be *places*, such as fields of the same struct, rather than method calls:
+/// since the macro borrows the places directly, the borrow checker can see that they are disjoint,
+/// whereas two calls to `&mut self` accessor methods would be rejected. No `unsafe` code is involved,
+/// so passing the same place twice is a compile-time error rather than undefined behavior.
+///
+/// Expands to a tuple `(&mut A, &mut B)`, where `A` and `B` are the value types.
+/// ```
+/// use sloth::{Lazy, borrow_disjoint_mut};
+///
+/// struct Caches<FA: FnOnce() -> Vec<i32>, FB: FnOnce() -> String> {
+///     numbers: Lazy<Vec<i32>, FA>,
+///     text:    Lazy<String, FB>
+/// }
+///
+/// let mut caches = Caches{
+///     numbers: Lazy::new(|| vec![1, 2, 3]),
+///     text:    Lazy::new(String::new)
+/// };
+///
+/// let (numbers, text) = borrow_disjoint_mut!(caches.numbers, caches.text);
+///
+/// numbers.push(4);
+/// text.push_str(&numbers.len().to_string());
+///
+/// assert_eq!(*caches.text, "4");
+/// ```
+///
+/// Passing the same place twice does not compile:
+/// ```compile_fail
+/// use sloth::{Lazy, borrow_disjoint_mut};
+///
+/// let mut lazy_value = Lazy::new(|| 5);
+///
+/// let (first, second) = borrow_disjoint_mut!(lazy_value, lazy_value);
+///
+/// *first += *second;
+/// ```
+#[macro_export]
+macro_rules! borrow_disjoint_mut {
+    ($first:expr, $second:expr $(,)?) => {
+        (
+            ::core::ops::DerefMut::deref_mut(&mut $first),
+            ::core::ops::DerefMut::deref_mut(&mut $second)
+        )
+    };
+}
@@ -0,0 +1,84 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+//
+// Interface
+//
+
+//
+// struct Memoized<K, V, F>
+//
+
+/// A wrapper for a single-argument function `F: Fn(&K) -> V`, which lazily evaluates
+/// and caches its result separately for each distinct key.
+///
+/// The function is invoked at most once per key, the first time the value for that key
+/// is requested via [`get()`](struct.Memoized.html#method.get), and never for keys,
+/// which are not requested. Cached values are handed out as `Rc<V>` handles.
+/// ```
+/// use sloth::Memoized;
+///
+/// let square_roots = Memoized::new(|n: &u32| f64::from(*n).sqrt());
+///
+/// assert_eq!(*square_roots.get(16), 4.0); // function is invoked here
+/// assert_eq!(*square_roots.get(16), 4.0); // but not here
+/// ```
+pub struct Memoized<K, V, F>
+    where F: Fn(&K) -> V
+{
+    function:    F,
+    values_cell: RefCell<HashMap<K, Rc<V>>>
+}
+
+//
+// Methods
+//
+
+impl<K, V, F> Memoized<K, V, F>
+    where K: Eq + Hash,
+          F: Fn(&K) -> V
+{
+    /// Constructs an instance, which will lazily obtain values from `function`
+    /// and cache them by key.
+    ///
+    /// This does **not** invoke `function`.
+    pub fn new(function: F) -> Self {
+        Self{
+            function,
+            values_cell: RefCell::new(HashMap::new())
+        }
+    }
+
+    /// Returns a shared handle to the value for `key`.
+    ///
+    /// This will invoke the function with `key` if the value for `key`
+    /// was not previously requested. The function may itself request values
+    /// for other keys from this instance, e.g. to memoize a recursive function.
+    pub fn get(&self, key: K) -> Rc<V> {
+        if let Some(value_rc) = self.values_cell.borrow().get(&key) {
+            return Rc::clone(value_rc);
+        }
+
+        let value = (self.function)(&key);
+
+        // If the function has already requested the value for this key, that value is kept,
+        // since handles to it might have been returned
+        Rc::clone(
+            self.values_cell
+                .borrow_mut()
+                .entry(key)
+                .or_insert_with(|| Rc::new(value))
+        )
+    }
+
+    /// Checks whether the value for `key` has already been evaluated and cached.
+    ///
+    /// This does **not** invoke the function.
+    #[must_use]
+    pub fn is_memoized(&self, key: &K) -> bool {
+        self.values_cell.borrow().contains_key(key)
+    }
+
+}
@@ -0,0 +1,312 @@
+use core::cell::{
+    RefCell,
+    OnceCell
+};
+use core::ops::{
+    Deref,
+    DerefMut
+};
+
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "std")]
+use std::time::{
+    Duration,
+    Instant
+};
+
+#[cfg(feature = "std")]
+use crate::Clock;
+
+//
+// Constants
+//
+
+const EXPECT_VALUE_CELL_INITIALIZED: &str = "value_cell must be initialized at this point";
+
+//
+// Types
+//
+
+#[cfg(feature = "alloc")]
+type EvictCallback<'a, T> = Box<dyn Fn(&T) + 'a>;
+// Without alloc, on_evict() is not available, so the callback is never set
+#[cfg(not(feature = "alloc"))]
+type EvictCallback<'a, T> = &'a dyn Fn(&T);
+
+//
+// Interface
+//
+
+//
+// struct RecomputingLazy<'a, T, Eval>: Deref<Target = T> + DerefMut + AsRef<T> + AsMut<T>
+//
+
+/// Contains a value of some type `T`, lazily evaluated using a stateful function
+/// or a closure (`FnMut() -> T`), which can be invoked again after the cached value
+/// is discarded via [`invalidate()`](struct.RecomputingLazy.html#method.invalidate).
+///
+/// The value is evaluated the first time this instance is dereferenced and cached
+/// until invalidated, after which the next dereference invokes the same evaluator again.
+///
+/// Unlike [`Lazy::reset_reusing()`](struct.Lazy.html#method.reset_reusing), which re-arms a `Lazy`
+/// with a fresh clone of its original evaluator, `RecomputingLazy` keeps invoking a single evaluator,
+/// so any state it captures mutably evolves across recomputations.
+/// ```
+/// use sloth::RecomputingLazy;
+///
+/// let mut generation = 0;
+/// let mut lazy_value = RecomputingLazy::new(move || {
+///     generation += 1;
+///     format!("generation {}", generation)
+/// });
+///
+/// assert_eq!(*lazy_value, "generation 1"); // evaluator is invoked here
+/// assert_eq!(*lazy_value, "generation 1"); // but not here
+///
+/// lazy_value.invalidate();
+///
+/// assert_eq!(*lazy_value, "generation 2"); // and here again
+/// ```
+///
+/// With the `std` feature, an instance constructed via [`new_debounced()`](struct.RecomputingLazy.html#method.new_debounced)
+/// collapses bursts of invalidations from a noisy source into a single recomputation.
+pub struct RecomputingLazy<'a, T, Eval>
+    where Eval: FnMut() -> T
+{
+    evaluator_cell: RefCell<Eval>,
+    value_cell:     OnceCell<T>,
+    on_evict:       Option<EvictCallback<'a, T>>,
+    #[cfg(feature = "std")]
+    debounce:       Option<Debounce<'a>>
+}
+
+//
+// struct Debounce<'a>
+//
+
+#[cfg(feature = "std")]
+struct Debounce<'a> {
+    window:            Duration,
+    clock:             Box<dyn Clock + 'a>,
+    last_invalidation: Option<Instant>
+}
+
+//
+// Trait impls
+//
+
+impl<T, Eval> Deref for RecomputingLazy<'_, T, Eval>
+    where Eval: FnMut() -> T
+{
+    type Target = T;
+
+    /// Immutable dereference, allowing access to the contained value.
+    ///
+    /// This will invoke evaluator function if this instance was not previously
+    /// dereferenced since construction or the last invalidation.
+    fn deref(&self) -> &T {
+        self.force()
+    }
+}
+
+impl<T, Eval> DerefMut for RecomputingLazy<'_, T, Eval>
+    where Eval: FnMut() -> T
+{
+    /// Mutable dereference, allowing access to the contained value.
+    ///
+    /// This will invoke evaluator function if this instance was not previously
+    /// dereferenced since construction or the last invalidation.
+    fn deref_mut(&mut self) -> &mut T {
+        self.force();
+
+        self.value_cell
+            .get_mut()
+            .expect(EXPECT_VALUE_CELL_INITIALIZED)
+    }
+}
+
+impl<T, Eval> AsRef<T> for RecomputingLazy<'_, T, Eval>
+    where Eval: FnMut() -> T
+{
+    /// Immutably borrows the evaluation result.
+    ///
+    /// This will invoke evaluator function if this instance was not previously
+    /// dereferenced since construction or the last invalidation.
+    fn as_ref(&self) -> &T {
+        self
+    }
+}
+
+impl<T, Eval> AsMut<T> for RecomputingLazy<'_, T, Eval>
+    where Eval: FnMut() -> T
+{
+    /// Mutably borrows the evaluation result.
+    ///
+    /// This will invoke evaluator function if this instance was not previously
+    /// dereferenced since construction or the last invalidation.
+    fn as_mut(&mut self) -> &mut T {
+        self
+    }
+}
+
+//
+// Methods
+//
+
+impl<'a, T, Eval> RecomputingLazy<'a, T, Eval>
+    where Eval: FnMut() -> T
+{
+    //
+    // Interface
+    //
+
+    /// Constructs a lazy `T` instance, whose value, if needed, will later be
+    /// obtained from `evaluator` and cached until invalidated.
+    ///
+    /// `evaluator` will be invoked only the first time this instance is dereferenced
+    /// and then once for each dereference following an invalidation.
+    pub fn new(evaluator: Eval) -> Self {
+        Self{
+            evaluator_cell: RefCell::new(evaluator),
+            value_cell:     OnceCell::new(),
+            on_evict:       None,
+            #[cfg(feature = "std")]
+            debounce:       None
+        }
+    }
+
+    /// Constructs a lazy `T` instance, like [`new()`](struct.RecomputingLazy.html#method.new) does,
+    /// but debounces invalidations: once the cached value is discarded via
+    /// [`invalidate()`](struct.RecomputingLazy.html#method.invalidate), further invalidations
+    /// within `window`, as measured by `clock`, are ignored.
+    ///
+    /// This way a burst of invalidations causes only one recomputation, on the next access,
+    /// even if the value is accessed in between. The value recomputed within `window`
+    /// is kept until the next invalidation after `window` has elapsed.
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use sloth::{RecomputingLazy, MockClock};
+    ///
+    /// let clock = MockClock::new();
+    ///
+    /// let mut generation = 0;
+    /// let mut lazy_value = RecomputingLazy::new_debounced(move || {
+    ///     generation += 1;
+    ///     generation
+    /// }, Duration::from_secs(1), &clock);
+    ///
+    /// assert_eq!(*lazy_value, 1);
+    ///
+    /// lazy_value.invalidate();
+    ///
+    /// assert_eq!(*lazy_value, 2); // evaluator is invoked here
+    ///
+    /// lazy_value.invalidate(); // but this invalidation is ignored
+    ///
+    /// assert_eq!(*lazy_value, 2);
+    ///
+    /// clock.advance(Duration::from_secs(1));
+    /// lazy_value.invalidate();
+    ///
+    /// assert_eq!(*lazy_value, 3); // and here again
+    /// ```
+    ///
+    /// This does **not** invoke evaluator function.
+    #[cfg(feature = "std")]
+    pub fn new_debounced<C>(evaluator: Eval, window: Duration, clock: C) -> Self
+        where C: Clock + 'a
+    {
+        Self{
+            debounce: Some(Debounce{
+                window,
+                clock:             Box::new(clock),
+                last_invalidation: None
+            }),
+            ..Self::new(evaluator)
+        }
+    }
+
+    /// Drops the cached value, if any, so that the value is evaluated again on next access.
+    ///
+    /// For an instance constructed via [`new_debounced()`](struct.RecomputingLazy.html#method.new_debounced),
+    /// this has no effect within the debounce window of the last invalidation, which discarded a value.
+    ///
+    /// This does **not** invoke evaluator function.
+    pub fn invalidate(&mut self) {
+        if !self.is_evaluated() || self.is_debounced() {
+            return;
+        }
+
+        if let Some(value) = self.value_cell.take() {
+            self.notify_evicted(&value);
+        }
+    }
+
+    /// Sets `callback` to be invoked with the cached value whenever it is discarded
+    /// via [`invalidate()`](struct.RecomputingLazy.html#method.invalidate),
+    /// replacing a previously set callback, if any.
+    ///
+    /// The value held by this instance when it is dropped is **not** passed to `callback`.
+    ///
+    /// This does **not** invoke evaluator function.
+    #[cfg(feature = "alloc")]
+    pub fn on_evict<F>(&mut self, callback: F)
+        where F: Fn(&T) + 'a
+    {
+        self.on_evict = Some(Box::new(callback));
+    }
+
+    /// Checks whether the value has been evaluated since construction or the last invalidation.
+    ///
+    /// This does **not** invoke evaluator function.
+    #[must_use]
+    pub fn is_evaluated(&self) -> bool {
+        self.value_cell.get().is_some()
+    }
+
+    //
+    // Service
+    //
+
+    fn force(&self) -> &T {
+        self.value_cell.get_or_init(|| (self.evaluator_cell.borrow_mut())())
+    }
+
+    #[cfg(feature = "std")]
+    fn is_debounced(&mut self) -> bool {
+        self.debounce
+            .as_mut()
+            .is_some_and(|debounce| !debounce.admit_invalidation())
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn is_debounced(&mut self) -> bool {
+        false
+    }
+
+    fn notify_evicted(&self, value: &T) {
+        if let Some(on_evict) = &self.on_evict {
+            on_evict(value);
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Debounce<'_> {
+    fn admit_invalidation(&mut self) -> bool {
+        let now = self.clock.now();
+
+        // Invalidations within the window are collapsed into the one already admitted
+        if let Some(last_invalidation) = self.last_invalidation {
+            if now.duration_since(last_invalidation) < self.window {
+                return false;
+            }
+        }
+
+        self.last_invalidation = Some(now);
+
+        true
+    }
+}
@@ -0,0 +1,128 @@
+use core::cell::{
+    Cell,
+    RefCell,
+    Ref,
+    OnceCell
+};
+use core::ops::Deref;
+
+//
+// Constants
+//
+
+const EXPECT_SEED_STILL_PRESENT:      &str = "seed must still be present at this point";
+const EXPECT_GENERATOR_STILL_PRESENT: &str = "generator must still be present at this point";
+
+//
+// Interface
+//
+
+//
+// struct SeededLazy<S, T, Gen>: Deref<Target = T> + AsRef<T>
+//
+
+/// Contains a value of some type `T`, lazily generated from a seed of type `S`
+/// by a function or a closure (`FnOnce(S) -> T`), e.g. a random number generator.
+///
+/// Unlike a [`Lazy<T, Eval>`](struct.Lazy.html), whose evaluator captures the seed,
+/// `SeededLazy` allows inspecting the pending seed via [`seed_ref()`](struct.SeededLazy.html#method.seed_ref)
+/// before the value is generated. The seed is passed to the generator by value the first time
+/// this instance is dereferenced.
+/// ```
+/// use sloth::SeededLazy;
+///
+/// let lazy_value = SeededLazy::new(42_u64, |seed| seed.wrapping_mul(6364136223846793005));
+///
+/// assert_eq!(lazy_value.seed_ref().as_deref(), Some(&42));
+///
+/// let value = *lazy_value; // generator is invoked here, consuming the seed
+///
+/// assert!(lazy_value.seed_ref().is_none());
+/// ```
+pub struct SeededLazy<S, T, Gen>
+    where Gen: FnOnce(S) -> T
+{
+    seed_cell:      RefCell<Option<S>>,
+    generator_cell: Cell<Option<Gen>>,
+    value_cell:     OnceCell<T>
+}
+
+//
+// Trait impls
+//
+
+impl<S, T, Gen> Deref for SeededLazy<S, T, Gen>
+    where Gen: FnOnce(S) -> T
+{
+    type Target = T;
+
+    /// Immutable dereference, allowing access to the contained value.
+    ///
+    /// This will invoke generator function with the seed
+    /// if this instance was not previously dereferenced.
+    fn deref(&self) -> &T {
+        self.value_cell.get_or_init(|| self.generate())
+    }
+}
+
+impl<S, T, Gen> AsRef<T> for SeededLazy<S, T, Gen>
+    where Gen: FnOnce(S) -> T
+{
+    /// Immutably borrows the generated value.
+    ///
+    /// This will invoke generator function with the seed
+    /// if this instance was not previously dereferenced.
+    fn as_ref(&self) -> &T {
+        self
+    }
+}
+
+//
+// Methods
+//
+
+impl<S, T, Gen> SeededLazy<S, T, Gen>
+    where Gen: FnOnce(S) -> T
+{
+    //
+    // Interface
+    //
+
+    /// Constructs a lazy `T` instance, whose value, if needed, will later be
+    /// obtained by passing `seed` to `generator` and cached.
+    ///
+    /// `generator` will be invoked only the first time this instance is dereferenced.
+    pub fn new(seed: S, generator: Gen) -> Self {
+        Self{
+            seed_cell:      RefCell::new(Some(seed)),
+            generator_cell: Cell::new(Some(generator)),
+            value_cell:     OnceCell::new()
+        }
+    }
+
+    /// Immutably borrows the seed, if the value has not been generated yet,
+    /// otherwise returns `None`.
+    ///
+    /// This does **not** invoke generator function. Dereferencing this instance
+    /// while the returned `Ref` is alive will panic.
+    #[must_use]
+    pub fn seed_ref(&self) -> Option<Ref<'_, S>> {
+        Ref::filter_map(self.seed_cell.borrow(), Option::as_ref).ok()
+    }
+
+    //
+    // Service
+    //
+
+    fn generate(&self) -> T {
+        let seed = self.seed_cell
+            .borrow_mut()
+            .take()
+            .expect(EXPECT_SEED_STILL_PRESENT);
+        let generator = self.generator_cell
+            .take()
+            .expect(EXPECT_GENERATOR_STILL_PRESENT);
+
+        generator(seed)
+    }
+}
@@ -0,0 +1,154 @@
+use alloc::rc::{
+    Rc,
+    Weak
+};
+use core::ops::Deref;
+use core::borrow::Borrow;
+
+use crate::{Lazy, BoxedLazy};
+
+//
+// Interface
+//
+
+//
+// struct SharedLazy<T>: Clone + Deref<Target = T> + AsRef<T> + Borrow<T>
+//
+
+/// A reference-counted handle to a lazily evaluated value of type `T`,
+/// which can be cloned to share a single evaluation between several owners.
+///
+/// The evaluator function, passed to [`SharedLazy::new()`](struct.SharedLazy.html#method.new),
+/// will be invoked at most once, the first time any of the clones is dereferenced.
+///
+/// Unlike [`Lazy<T, Eval>`](struct.Lazy.html), `SharedLazy` only provides immutable access to its value.
+/// ```
+/// use sloth::SharedLazy;
+///
+/// let lazy_value = SharedLazy::new(|| vec![1, 2, 3]);
+/// let lazy_value_clone = lazy_value.clone();
+///
+/// assert_eq!(lazy_value_clone.len(), 3); // evaluator is invoked here
+/// assert_eq!(*lazy_value, vec![1, 2, 3]); // and not here
+/// ```
+pub struct SharedLazy<T> {
+    lazy_rc: Rc<BoxedLazy<T>>
+}
+
+//
+// struct WeakSharedLazy<T>: Clone
+//
+
+/// A non-owning handle to a [`SharedLazy<T>`](struct.SharedLazy.html) value,
+/// obtained via [`SharedLazy::downgrade()`](struct.SharedLazy.html#method.downgrade).
+///
+/// A `WeakSharedLazy` does not keep the shared value (or its evaluator) alive,
+/// but can be [upgraded](struct.WeakSharedLazy.html#method.upgrade) to a `SharedLazy`
+/// as long as at least one `SharedLazy` handle to the same value exists.
+///
+/// For [`SyncLazy<T, Eval>`](struct.SyncLazy.html) values shared via `Arc`,
+/// [`Arc::downgrade()`](https://doc.rust-lang.org/std/sync/struct.Arc.html#method.downgrade)
+/// serves the same purpose.
+pub struct WeakSharedLazy<T> {
+    lazy_weak: Weak<BoxedLazy<T>>
+}
+
+//
+// Trait impls
+//
+
+impl<T> Clone for SharedLazy<T> {
+    /// Creates another handle to the same lazily evaluated value.
+    ///
+    /// This does **not** invoke evaluator function.
+    fn clone(&self) -> Self {
+        Self{
+            lazy_rc: Rc::clone(&self.lazy_rc)
+        }
+    }
+}
+
+impl<T> Deref for SharedLazy<T> {
+    type Target = T;
+
+    /// Immutable dereference, allowing access to the shared value.
+    ///
+    /// This will invoke evaluator function if none of the clones
+    /// were previously dereferenced.
+    fn deref(&self) -> &T {
+        &self.lazy_rc
+    }
+}
+
+impl<T> AsRef<T> for SharedLazy<T> {
+    /// Immutably borrows the shared evaluation result.
+    ///
+    /// This will invoke evaluator function if none of the clones
+    /// were previously dereferenced.
+    fn as_ref(&self) -> &T {
+        &self.lazy_rc
+    }
+}
+
+impl<T> Borrow<T> for SharedLazy<T> {
+    /// Immutably borrows the shared evaluation result.
+    ///
+    /// This will invoke evaluator function if none of the clones
+    /// were previously dereferenced.
+    fn borrow(&self) -> &T {
+        &self.lazy_rc
+    }
+}
+
+impl<T> Clone for WeakSharedLazy<T> {
+    /// Creates another non-owning handle to the same value.
+    fn clone(&self) -> Self {
+        Self{
+            lazy_weak: Weak::clone(&self.lazy_weak)
+        }
+    }
+}
+
+//
+// Methods
+//
+
+impl<T> SharedLazy<T> {
+    /// Constructs a shared lazy `T` instance, whose value, if needed, will later be
+    /// obtained from `evaluator` and cached for all clones of this instance.
+    ///
+    /// `evaluator` will be invoked only the first time this instance or any of its clones
+    /// is dereferenced.
+    pub fn new<Eval>(evaluator: Eval) -> Self
+        where Eval: FnOnce() -> T + 'static
+    {
+        Self{
+            lazy_rc: Rc::new(Lazy::new_boxed(evaluator))
+        }
+    }
+
+    /// Creates a non-owning [`WeakSharedLazy<T>`](struct.WeakSharedLazy.html) handle to the value of `this`.
+    ///
+    /// This is an associated function, rather than a method, so as not to shadow
+    /// methods of `T`, accessible via dereference.
+    ///
+    /// This does **not** invoke evaluator function.
+    pub fn downgrade(this: &Self) -> WeakSharedLazy<T> {
+        WeakSharedLazy{
+            lazy_weak: Rc::downgrade(&this.lazy_rc)
+        }
+    }
+}
+
+impl<T> WeakSharedLazy<T> {
+    /// Attempts to obtain a [`SharedLazy<T>`](struct.SharedLazy.html) handle to the value,
+    /// returning `None` if all `SharedLazy` handles to it have been dropped.
+    ///
+    /// This does **not** invoke evaluator function.
+    #[must_use]
+    pub fn upgrade(&self) -> Option<SharedLazy<T>> {
+        self.lazy_weak
+            .upgrade()
+            .map(|lazy_rc| SharedLazy{lazy_rc})
+    }
+}
@@ -0,0 +1,120 @@
+use std::sync::{
+    Arc,
+    Mutex
+};
+
+use arc_swap::ArcSwapOption;
+
+//
+// Constants
+//
+
+const EXPECT_EVALUATOR_STILL_PRESENT:    &str = "evaluator must still be present at this point";
+const EXPECT_EVALUATOR_MUTEX_UNPOISONED: &str = "evaluator_mutex must not be poisoned, which only happens if evaluator panicked";
+
+//
+// Interface
+//
+
+//
+// struct SwappableLazy<T, Eval>
+//
+
+/// A thread-safe lazily evaluated value of type `T`, which, once available,
+/// can be atomically replaced from any thread.
+///
+/// The evaluator function is invoked at most once, the first time the value is
+/// [loaded](struct.SwappableLazy.html#method.load), unless a value has been [stored](struct.SwappableLazy.html#method.store) before that.
+/// Readers get the value as an `Arc<T>`, so a value loaded before a replacement stays consistent
+/// and alive for as long as the reader holds it.
+///
+/// This struct is only available with the `arc-swap` feature.
+/// ```
+/// use sloth::SwappableLazy;
+///
+/// let lazy_config = SwappableLazy::new(|| "initial".to_string());
+///
+/// let old_config = lazy_config.load(); // evaluator is invoked here
+///
+/// lazy_config.store("replaced".to_string());
+///
+/// assert_eq!(*old_config, "initial");
+/// assert_eq!(*lazy_config.load(), "replaced");
+/// ```
+pub struct SwappableLazy<T, Eval>
+    where Eval: FnOnce() -> T
+{
+    evaluator_mutex: Mutex<Option<Eval>>,
+    value_swap:      ArcSwapOption<T>
+}
+
+//
+// Methods
+//
+
+impl<T, Eval> SwappableLazy<T, Eval>
+    where Eval: FnOnce() -> T
+{
+    /// Constructs a thread-safe lazy `T` instance, whose value, if needed, will later be
+    /// obtained from `evaluator` and cached.
+    ///
+    /// `evaluator` will be invoked only the first time the value is loaded from any thread,
+    /// if no value has been stored before that.
+    pub fn new(evaluator: Eval) -> Self {
+        Self{
+            evaluator_mutex: Mutex::new(Some(evaluator)),
+            value_swap:      ArcSwapOption::empty()
+        }
+    }
+
+    /// Returns the current value.
+    ///
+    /// This will invoke evaluator function if the value has neither been loaded nor stored before.
+    /// Threads loading the value during evaluation block until it is available.
+    #[must_use]
+    pub fn load(&self) -> Arc<T> {
+        if let Some(value) = self.value_swap.load_full() {
+            return value;
+        }
+
+        let mut evaluator_option = self.evaluator_mutex
+            .lock()
+            .expect(EXPECT_EVALUATOR_MUTEX_UNPOISONED);
+
+        if let Some(value) = self.value_swap.load_full() {
+            return value;
+        }
+
+        let evaluator = evaluator_option
+            .take()
+            .expect(EXPECT_EVALUATOR_STILL_PRESENT);
+
+        let evaluated_value = Arc::new(evaluator());
+
+        // A value stored by another thread during evaluation takes precedence
+        let previous_value = self.value_swap.rcu(|current_value| {
+            current_value
+                .clone()
+                .or_else(|| Some(Arc::clone(&evaluated_value)))
+        });
+
+        previous_value.unwrap_or(evaluated_value)
+    }
+
+    /// Atomically replaces the current value with `value`.
+    ///
+    /// If the value has not been evaluated yet, the evaluator will never be invoked.
+    /// If the value is being evaluated by another thread, the evaluation result is discarded
+    /// in favour of `value`.
+    pub fn store(&self, value: T) {
+        self.value_swap.store(Some(Arc::new(value)));
+    }
+
+    /// Checks whether a value is currently available, either evaluated or stored.
+    ///
+    /// This does **not** invoke evaluator function.
+    #[must_use]
+    pub fn is_evaluated(&self) -> bool {
+        self.value_swap.load().is_some()
+    }
+}
@@ -0,0 +1,145 @@
+use std::sync::{
+    Arc,
+    Mutex,
+    OnceLock
+};
+use std::ops::Deref;
+
+//
+// Constants
+//
+
+const EXPECT_ARMED_ON_ACCESS:            &str = "SyncLazy holds neither a value nor an evaluator, since its evaluator panicked during a previous access";
+const EXPECT_EVALUATOR_MUTEX_UNPOISONED: &str = "evaluator_mutex must not be poisoned, since it is never held during evaluation";
+
+//
+// Interface
+//
+
+//
+// struct SyncLazy<T, Eval>: Deref<Target = T> + AsRef<T>
+//
+
+/// A thread-safe counterpart of [`Lazy<T, Eval>`](struct.Lazy.html), which can be shared between threads.
+///
+/// If several threads dereference the same `SyncLazy` at the same time, only one of them
+/// will invoke the evaluator function, while the others block until the value is available.
+///
+/// Unlike [`Lazy<T, Eval>`](struct.Lazy.html), `SyncLazy` only provides immutable access to its value.
+/// ```
+/// use std::thread;
+///
+/// use sloth::new_shared_sync;
+///
+/// let lazy_value = new_shared_sync(|| 2 + 2);
+///
+/// let lazy_value_clone = lazy_value.clone();
+/// let handle = thread::spawn(move || **lazy_value_clone * 10);
+///
+/// assert_eq!(**lazy_value, 4);
+/// assert_eq!(handle.join().unwrap(), 40);
+/// ```
+///
+/// Since [`SyncLazy::new()`](struct.SyncLazy.html#method.new) is a `const fn`,
+/// a `SyncLazy` with a function pointer evaluator can be stored in a `static`:
+/// ```
+/// use sloth::SyncLazy;
+///
+/// fn load_greeting() -> String {
+///     "hello".to_string()
+/// }
+///
+/// static GREETING: SyncLazy<String, fn() -> String> = SyncLazy::new(load_greeting);
+///
+/// assert_eq!(GREETING.as_str(), "hello");
+/// ```
+pub struct SyncLazy<T, Eval>
+    where Eval: FnOnce() -> T
+{
+    evaluator_mutex: Mutex<Option<Eval>>,
+    value_lock:      OnceLock<T>
+}
+
+//
+// Trait impls
+//
+
+impl<T, Eval> Deref for SyncLazy<T, Eval>
+    where Eval: FnOnce() -> T
+{
+    type Target = T;
+
+    /// Immutable dereference, allowing access to the contained value.
+    ///
+    /// This will invoke evaluator function if no thread
+    /// has previously dereferenced this instance.
+    fn deref(&self) -> &T {
+        self.value_lock.get_or_init(|| self.evaluate())
+    }
+}
+
+impl<T, Eval> AsRef<T> for SyncLazy<T, Eval>
+    where Eval: FnOnce() -> T
+{
+    /// Immutably borrows the evaluation result.
+    ///
+    /// This will invoke evaluator function if no thread
+    /// has previously dereferenced this instance.
+    fn as_ref(&self) -> &T {
+        self
+    }
+}
+
+//
+// Methods
+//
+
+impl<T, Eval> SyncLazy<T, Eval>
+    where Eval: FnOnce() -> T
+{
+    //
+    // Interface
+    //
+
+    /// Constructs a thread-safe lazy `T` instance, whose value, if needed, will later be
+    /// obtained from `evaluator` and cached.
+    ///
+    /// `evaluator` will be invoked only the first time this instance is dereferenced
+    /// from any thread.
+    pub const fn new(evaluator: Eval) -> Self {
+        Self{
+            evaluator_mutex: Mutex::new(Some(evaluator)),
+            value_lock:      OnceLock::new()
+        }
+    }
+
+    //
+    // Service
+    //
+
+    fn evaluate(&self) -> T {
+        let evaluator = self.evaluator_mutex
+            .lock()
+            .expect(EXPECT_EVALUATOR_MUTEX_UNPOISONED)
+            .take()
+            .expect(EXPECT_ARMED_ON_ACCESS);
+
+        evaluator()
+    }
+}
+
+//
+// Functions
+//
+
+/// Constructs a [`SyncLazy<T, Eval>`](struct.SyncLazy.html) wrapped in an `Arc`,
+/// ready to be cloned and sent to other threads.
+///
+/// `evaluator` will be invoked only the first time the value is dereferenced
+/// via any of the `Arc` clones.
+pub fn new_shared_sync<T, Eval>(evaluator: Eval) -> Arc<SyncLazy<T, Eval>>
+    where T:    Send + Sync,
+          Eval: FnOnce() -> T + Send
+{
+    Arc::new(SyncLazy::new(evaluator))
+}
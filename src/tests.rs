@@ -4,6 +4,36 @@ use std::borrow::{
     Borrow,
     BorrowMut
 };
+use std::cell::{
+    Cell,
+    RefCell,
+    OnceCell
+};
+use std::collections::{
+    HashMap,
+    HashSet
+};
+use std::rc::{
+    Rc,
+    Weak
+};
+use std::cmp;
+use std::iter;
+use std::io::Read;
+use std::panic::{
+    self,
+    AssertUnwindSafe
+};
+use std::thread;
+use std::time::Duration;
+use std::sync::{
+    Arc,
+    mpsc
+};
+use std::sync::atomic::{
+    AtomicUsize,
+    Ordering
+};
 
 //
 // Macros
@@ -298,29 +328,3689 @@ fn lazy_value_no_drop_on_unwrap() {
     assert!(was_value_dropped);
 }
 
-//
-// Service
-//
+#[test]
+fn lazy_string_eq_ignore_ascii_case_match() {
+    let mut evaluator_call_count = 0;
 
-fn is_borrow_eq<T, Q>(borrowed: &Q, expected: &T) -> bool
-    where   T: PartialEq,
-            Q: Borrow<T>
-{
-    borrowed.borrow() == expected.borrow()
+    let lazy_value = Lazy::new(|| {
+        evaluator_call_count += 1;
+        "Some String".to_string()
+    });
+
+    assert!(lazy_value.eq_ignore_ascii_case("some string"));
+    assert!(lazy_value.eq_ignore_ascii_case("SOME STRING"));
+    assert!(lazy_value.eq_ignore_ascii_case("Some String"));
+
+    assert_eq!(evaluator_call_count, 1);
 }
 
-//
-// Service types
-//
+#[test]
+fn lazy_string_eq_ignore_ascii_case_mismatch() {
+    let mut evaluator_call_count = 0;
 
-struct SomethingDroppable<'a> {
-    was_dropped: &'a mut bool
+    let lazy_value = Lazy::new(|| {
+        evaluator_call_count += 1;
+        "Some String".to_string()
+    });
+
+    assert!(!lazy_value.eq_ignore_ascii_case("some other string"));
+    assert!(!lazy_value.eq_ignore_ascii_case("some strin"));
+
+    assert_eq!(evaluator_call_count, 1);
 }
 
-impl Drop for SomethingDroppable<'_> {
-    fn drop(&mut self) {
-        assert!(!*self.was_dropped, "was_dropped must initially be false");
+#[test]
+fn lazy_take_evaluator_if_unevaluated() {
+    let mut evaluator_call_count = 0;
 
-        *self.was_dropped = true;
+    let mut lazy_value = Lazy::new(|| {
+        evaluator_call_count += 1;
+        15
+    });
+
+    let evaluator = lazy_value.take_evaluator().expect("evaluator must be present");
+
+    assert!(lazy_value.take_evaluator().is_none());
+    assert_eq!(evaluator(), 15);
+    assert_eq!(evaluator_call_count, 1);
+}
+
+#[test]
+fn lazy_take_evaluator_if_evaluated() {
+    let mut lazy_value = Lazy::new(|| 15);
+
+    assert_eq!(*lazy_value, 15);
+    assert!(lazy_value.take_evaluator().is_none());
+    assert_eq!(*lazy_value, 15);
+}
+
+#[test]
+#[should_panic]
+fn lazy_take_evaluator_then_deref() {
+    let mut lazy_value = Lazy::new(|| 15);
+
+    let _ = lazy_value.take_evaluator();
+
+    let _ = *lazy_value;
+}
+
+#[test]
+fn lazy_arm_if_unarmed() {
+    let mut evaluator_call_count = 0;
+
+    let mut lazy_value = Lazy::new(|| {
+        evaluator_call_count += 1;
+        30
+    });
+
+    let evaluator = lazy_value.take_evaluator().expect("evaluator must be present");
+
+    assert!(lazy_value.arm(evaluator).is_ok());
+    assert_eq!(*lazy_value, 30);
+    assert_eq!(*lazy_value, 30);
+    assert_eq!(evaluator_call_count, 1);
+}
+
+#[test]
+fn lazy_arm_if_armed() {
+    fn get_value() -> i32 { 30 }
+    fn get_other_value() -> i32 { -30 }
+
+    let mut lazy_value: Lazy<i32, fn() -> i32> = Lazy::new(get_value);
+
+    assert!(lazy_value.arm(get_other_value).is_err());
+    assert_eq!(*lazy_value, 30);
+}
+
+#[test]
+fn lazy_arm_if_evaluated() {
+    fn get_value() -> i32 { 30 }
+    fn get_other_value() -> i32 { -30 }
+
+    let mut lazy_value: Lazy<i32, fn() -> i32> = Lazy::new(get_value);
+
+    assert_eq!(*lazy_value, 30);
+    assert!(lazy_value.arm(get_other_value).is_err());
+    assert_eq!(*lazy_value, 30);
+}
+
+#[test]
+fn lazy_catch_ok() {
+    let evaluator_call_count = AtomicUsize::new(0);
+
+    let lazy_value = Lazy::new(|| {
+        evaluator_call_count.fetch_add(1, Ordering::SeqCst);
+        75
+    }).catch();
+
+    assert_eq!(*lazy_value, Ok(75));
+    assert_eq!(*lazy_value, Ok(75));
+    assert_eq!(evaluator_call_count.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn lazy_catch_panic() {
+    let evaluator_call_count = AtomicUsize::new(0);
+
+    let lazy_value = Lazy::new(|| -> i32 {
+        evaluator_call_count.fetch_add(1, Ordering::SeqCst);
+        panic!("evaluator failed with code {}", 13)
+    }).catch();
+
+    assert_eq!(*lazy_value, Err("evaluator failed with code 13".to_string()));
+    assert_eq!(*lazy_value, Err("evaluator failed with code 13".to_string()));
+    assert_eq!(evaluator_call_count.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn lazy_catch_never_called_if_unused() {
+    let evaluator_call_count = AtomicUsize::new(0);
+
+    let _lazy_value = Lazy::new(|| {
+        evaluator_call_count.fetch_add(1, Ordering::SeqCst);
+        75
+    }).catch();
+
+    assert_eq!(evaluator_call_count.load(Ordering::SeqCst), 0);
+}
+
+#[test]
+fn shared_lazy_single_evaluation() {
+    let evaluator_call_count = Rc::new(Cell::new(0));
+
+    let lazy_value = {
+        let evaluator_call_count = Rc::clone(&evaluator_call_count);
+
+        SharedLazy::new(move || {
+            evaluator_call_count.set(evaluator_call_count.get() + 1);
+            "shared string".to_string()
+        })
+    };
+
+    let lazy_value_clones = [lazy_value.clone(), lazy_value.clone(), lazy_value.clone()];
+
+    assert_eq!(evaluator_call_count.get(), 0);
+
+    assert_eq!(*lazy_value_clones[1], "shared string");
+    assert_eq!(evaluator_call_count.get(), 1);
+
+    for lazy_value_clone in lazy_value_clones.iter() {
+        assert_eq!(**lazy_value_clone, "shared string");
+    }
+    assert_eq!(*lazy_value, "shared string");
+    assert_eq!(lazy_value.as_ref(), "shared string");
+    assert!(is_borrow_eq(&lazy_value, &"shared string".to_string()));
+
+    assert_eq!(evaluator_call_count.get(), 1);
+}
+
+#[test]
+fn shared_lazy_evaluator_never_called_if_unused() {
+    let evaluator_call_count = Rc::new(Cell::new(0));
+
+    {
+        let evaluator_call_count = Rc::clone(&evaluator_call_count);
+
+        let lazy_value = SharedLazy::new(move || {
+            evaluator_call_count.set(evaluator_call_count.get() + 1);
+            25
+        });
+
+        let _lazy_value_clone = lazy_value.clone();
+    }
+
+    assert_eq!(evaluator_call_count.get(), 0);
+}
+
+#[test]
+fn lazy_state_lifecycle() {
+    let mut lazy_value = Lazy::new(|| 40);
+
+    assert_eq!(lazy_value.state(), LazyState::Unevaluated);
+
+    let evaluator = lazy_value.take_evaluator().expect("evaluator must be present");
+
+    assert_eq!(lazy_value.state(), LazyState::Unarmed);
+
+    assert!(lazy_value.arm(evaluator).is_ok());
+
+    assert_eq!(lazy_value.state(), LazyState::Unevaluated);
+    assert_eq!(lazy_value.state(), LazyState::Unevaluated);
+
+    assert_eq!(*lazy_value, 40);
+
+    assert_eq!(lazy_value.state(), LazyState::Evaluated);
+}
+
+#[test]
+fn lazy_state_never_evaluates() {
+    let mut evaluator_call_count = 0;
+
+    let lazy_value = Lazy::new(|| {
+        evaluator_call_count += 1;
+        40
+    });
+
+    assert_eq!(lazy_value.state(), LazyState::Unevaluated);
+    assert_eq!(lazy_value.state(), LazyState::Unevaluated);
+    assert_eq!(evaluator_call_count, 0);
+}
+
+#[test]
+fn lazy_state_while_borrowed() {
+    let mut lazy_value = Lazy::new(|| 40);
+
+    let value_mut = lazy_value.value_mut();
+
+    assert_eq!(*value_mut, 40);
+
+    let value_ref = lazy_value.value_ref();
+
+    assert_eq!(lazy_value.state(), LazyState::Evaluated);
+    assert_eq!(*value_ref, 40);
+}
+
+#[test]
+fn cached_evaluates_once_per_key() {
+    let cache = RefCell::new(HashMap::new());
+    let mut evaluated_keys = Vec::new();
+
+    let mut square = |n: i32| cached(&cache, n, || {
+        evaluated_keys.push(n);
+        n * n
+    });
+
+    assert_eq!(square(3), 9);
+    assert_eq!(square(4), 16);
+    assert_eq!(square(3), 9);
+    assert_eq!(square(4), 16);
+    assert_eq!(square(5), 25);
+
+    assert_eq!(evaluated_keys, vec![3, 4, 5]);
+    assert_eq!(cache.borrow().len(), 3);
+}
+
+#[test]
+fn cached_uses_prefilled_value() {
+    let cache = RefCell::new(HashMap::new());
+
+    cache.borrow_mut().insert("key", "prefilled".to_string());
+
+    assert_eq!(cached(&cache, "key", || panic!("evaluator must not be called")), "prefilled");
+}
+
+#[test]
+fn lazy_access_timed() {
+    let mut evaluator_call_count = 0;
+
+    let lazy_value = Lazy::new(|| {
+        evaluator_call_count += 1;
+        thread::sleep(Duration::from_millis(10));
+        60
+    });
+
+    let (value, evaluation_duration) = lazy_value.access_timed();
+
+    assert_eq!(*value, 60);
+    assert!(evaluation_duration.expect("evaluation must be timed") >= Duration::from_millis(10));
+
+    let (value, evaluation_duration) = lazy_value.access_timed();
+
+    assert_eq!(*value, 60);
+    assert!(evaluation_duration.is_none());
+    assert_eq!(evaluator_call_count, 1);
+}
+
+#[test]
+fn lazy_access_timed_if_evaluated() {
+    let lazy_value = Lazy::new(|| 60);
+
+    assert_eq!(*lazy_value, 60);
+    assert_eq!(lazy_value.access_timed(), (&60, None));
+}
+
+#[test]
+fn sync_lazy_shared_between_threads() {
+    let evaluator_call_count = Arc::new(AtomicUsize::new(0));
+
+    let lazy_value = {
+        let evaluator_call_count = Arc::clone(&evaluator_call_count);
+
+        new_shared_sync(move || {
+            evaluator_call_count.fetch_add(1, Ordering::SeqCst);
+            thread::sleep(Duration::from_millis(10));
+            "shared between threads".to_string()
+        })
+    };
+
+    let handles: Vec<_> = (0..8)
+        .map(|_| {
+            let lazy_value = Arc::clone(&lazy_value);
+
+            thread::spawn(move || lazy_value.len())
+        })
+        .collect();
+
+    for handle in handles {
+        assert_eq!(handle.join().expect("thread must not panic"), 22);
+    }
+
+    assert_eq!(**lazy_value, "shared between threads");
+    assert_eq!(evaluator_call_count.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn sync_lazy_evaluator_never_called_if_unused() {
+    let evaluator_call_count = AtomicUsize::new(0);
+
+    let _lazy_value = SyncLazy::new(|| evaluator_call_count.fetch_add(1, Ordering::SeqCst));
+
+    assert_eq!(evaluator_call_count.load(Ordering::SeqCst), 0);
+}
+
+#[test]
+#[should_panic(expected = "evaluator panicked during a previous access")]
+fn sync_lazy_access_after_evaluator_panic() {
+    let lazy_value = SyncLazy::new(|| -> i32 { panic!("evaluator failed") });
+
+    let access_result = panic::catch_unwind(AssertUnwindSafe(|| *lazy_value));
+
+    assert!(access_result.is_err());
+
+    let _value = *lazy_value;
+}
+
+#[test]
+fn lazy_vec_extract_if() {
+    let mut evaluator_call_count = 0;
+
+    let mut lazy_value = Lazy::new(|| {
+        evaluator_call_count += 1;
+        vec![1, 2, 3, 4, 5, 6, 7]
+    });
+
+    assert_eq!(lazy_value.extract_if(|n| n % 2 == 0), vec![2, 4, 6]);
+    assert_eq!(*lazy_value, vec![1, 3, 5, 7]);
+
+    assert_eq!(lazy_value.extract_if(|n| *n > 10), Vec::<i32>::new());
+    assert_eq!(*lazy_value, vec![1, 3, 5, 7]);
+
+    assert_eq!(lazy_value.extract_if(|_| true), vec![1, 3, 5, 7]);
+    assert!(lazy_value.is_empty());
+
+    assert_eq!(evaluator_call_count, 1);
+}
+
+#[test]
+fn fallback_lazy_stops_at_first_some() {
+    let evaluator_calls = RefCell::new(Vec::new());
+
+    let lazy_value = FallbackLazy::new()
+        .push(|| { evaluator_calls.borrow_mut().push(1); None })
+        .push(|| { evaluator_calls.borrow_mut().push(2); None })
+        .push(|| { evaluator_calls.borrow_mut().push(3); Some("third") })
+        .push(|| { evaluator_calls.borrow_mut().push(4); Some("fourth") })
+        .build();
+
+    assert!(evaluator_calls.borrow().is_empty());
+
+    assert_eq!(*lazy_value, Some("third"));
+    assert_eq!(*lazy_value, Some("third"));
+
+    assert_eq!(*evaluator_calls.borrow(), vec![1, 2, 3]);
+}
+
+#[test]
+fn fallback_lazy_all_none() {
+    let evaluator_calls = RefCell::new(Vec::new());
+
+    let lazy_value = FallbackLazy::<i32>::new()
+        .push(|| { evaluator_calls.borrow_mut().push(1); None })
+        .push(|| { evaluator_calls.borrow_mut().push(2); None })
+        .build();
+
+    assert_eq!(*lazy_value, None);
+    assert_eq!(*lazy_value, None);
+
+    assert_eq!(*evaluator_calls.borrow(), vec![1, 2]);
+}
+
+#[test]
+fn fallback_lazy_empty() {
+    let lazy_value = FallbackLazy::<i32>::default().build();
+
+    assert_eq!(*lazy_value, None);
+}
+
+#[test]
+fn lazy_reset_reusing() {
+    let evaluator_call_count = Cell::new(0);
+
+    let mut lazy_value = Lazy::new_reusable(|| {
+        evaluator_call_count.set(evaluator_call_count.get() + 1);
+        evaluator_call_count.get() * 100
+    });
+
+    assert_eq!(*lazy_value, 100);
+    assert_eq!(*lazy_value, 100);
+
+    lazy_value.reset_reusing();
+
+    assert_eq!(evaluator_call_count.get(), 1);
+    assert_eq!(lazy_value.state(), LazyState::Unevaluated);
+
+    assert_eq!(*lazy_value, 200);
+    assert_eq!(*lazy_value, 200);
+
+    lazy_value.reset_reusing();
+    lazy_value.reset_reusing();
+
+    assert_eq!(*lazy_value, 300);
+    assert_eq!(evaluator_call_count.get(), 3);
+}
+
+#[test]
+fn lazy_reset_reusing_if_unevaluated() {
+    let evaluator_call_count = Cell::new(0);
+
+    let mut lazy_value = Lazy::new(|| {
+        evaluator_call_count.set(evaluator_call_count.get() + 1);
+        100
+    });
+
+    lazy_value.reset_reusing();
+
+    assert_eq!(*lazy_value, 100);
+    assert_eq!(evaluator_call_count.get(), 1);
+}
+
+#[test]
+#[allow(unused_must_use)]
+fn lazy_reset_reusing_drops_value() {
+    let drop_count = Cell::new(0);
+
+    {
+        let mut lazy_value = Lazy::new_reusable(|| SomethingDropCounting{drop_count: &drop_count});
+
+        lazy_value.as_ref();
+        lazy_value.reset_reusing();
+
+        assert_eq!(drop_count.get(), 1);
+
+        lazy_value.as_ref();
+    }
+
+    assert_eq!(drop_count.get(), 2);
+}
+
+#[test]
+#[should_panic(expected = "new_reusable")]
+fn lazy_reset_reusing_without_template() {
+    let mut lazy_value = Lazy::new(|| 100);
+
+    assert_eq!(*lazy_value, 100);
+
+    lazy_value.reset_reusing();
+}
+
+#[test]
+fn lazy_result_as_result_ok() {
+    let mut evaluator_call_count = 0;
+
+    let lazy_value = Lazy::new(|| -> Result<i32, String> {
+        evaluator_call_count += 1;
+        Ok(15)
+    });
+
+    assert_eq!(lazy_value.as_result(), Ok(&15));
+    assert_eq!(lazy_value.as_result(), Ok(&15));
+    assert_eq!(evaluator_call_count, 1);
+}
+
+#[test]
+fn lazy_result_as_result_err() {
+    let mut evaluator_call_count = 0;
+
+    let lazy_value = Lazy::new(|| -> Result<i32, String> {
+        evaluator_call_count += 1;
+        Err("failed".to_string())
+    });
+
+    assert_eq!(lazy_value.as_result(), Err(&"failed".to_string()));
+    assert_eq!(lazy_value.as_result(), Err(&"failed".to_string()));
+    assert_eq!(evaluator_call_count, 1);
+}
+
+#[test]
+fn lazy_contains() {
+    let mut evaluator_call_count = 0;
+
+    let lazy_value = Lazy::new(|| {
+        evaluator_call_count += 1;
+        "some string".to_string()
+    });
+
+    assert!(lazy_value.contains(&"some string".to_string()));
+    assert!(!lazy_value.contains(&"other string".to_string()));
+    assert!(!lazy_value.contains(&String::new()));
+    assert_eq!(evaluator_call_count, 1);
+}
+
+#[test]
+fn lazy_new_with_hook() {
+    let hook_calls = RefCell::new(Vec::new());
+    let evaluator_call_count = Cell::new(0);
+
+    let lazy_value = Lazy::new_with_hook(
+        "answer",
+        |label| hook_calls.borrow_mut().push(label),
+        || {
+            evaluator_call_count.set(evaluator_call_count.get() + 1);
+            42
+        }
+    );
+
+    assert!(hook_calls.borrow().is_empty());
+
+    assert_eq!(*lazy_value, 42);
+    assert_eq!(*lazy_value, 42);
+
+    assert_eq!(*hook_calls.borrow(), vec!["answer"]);
+    assert_eq!(evaluator_call_count.get(), 1);
+}
+
+#[test]
+#[allow(unused_variables)]
+fn lazy_new_with_hook_never_called_if_unused() {
+    let hook_calls = RefCell::new(Vec::new());
+
+    {
+        let lazy_value = Lazy::new_with_hook("answer", |label| hook_calls.borrow_mut().push(label), || 42);
+    }
+
+    assert!(hook_calls.borrow().is_empty());
+}
+
+#[test]
+fn lazy_into_iter_once() {
+    let mut evaluator_call_count = 0;
+
+    let lazy_value = Lazy::new(|| {
+        evaluator_call_count += 1;
+        3
+    });
+
+    let values: Vec<_> = vec![1, 2].into_iter()
+        .chain(lazy_value.into_iter_once())
+        .chain(vec![4, 5])
+        .collect();
+
+    assert_eq!(values, vec![1, 2, 3, 4, 5]);
+    assert_eq!(evaluator_call_count, 1);
+}
+
+#[test]
+fn lazy_into_inner_or_default_if_evaluated() {
+    let mut evaluator_call_count = 0;
+
+    let lazy_value = Lazy::new(|| {
+        evaluator_call_count += 1;
+        vec![1, 2, 3]
+    });
+
+    assert_eq!(lazy_value.len(), 3);
+    assert_eq!(lazy_value.into_inner_or_default(), vec![1, 2, 3]);
+    assert_eq!(evaluator_call_count, 1);
+}
+
+#[test]
+fn lazy_into_inner_or_default_if_unevaluated() {
+    let evaluator_call_count = Cell::new(0);
+    let evaluator_drop_count = Cell::new(0);
+
+    let evaluator_probe = SomethingDropCounting{drop_count: &evaluator_drop_count};
+    let evaluator_call_count_ref = &evaluator_call_count;
+
+    let lazy_value = Lazy::new(move || {
+        let _evaluator_probe = evaluator_probe;
+        evaluator_call_count_ref.set(evaluator_call_count_ref.get() + 1);
+        vec![1, 2, 3]
+    });
+
+    assert_eq!(lazy_value.into_inner_or_default(), Vec::<i32>::new());
+    assert_eq!(evaluator_call_count.get(), 0);
+    assert_eq!(evaluator_drop_count.get(), 1);
+}
+
+#[test]
+fn lazy_project_ref() {
+    let parent_evaluator_call_count = Cell::new(0);
+    let projection_call_count = Cell::new(0);
+
+    let lazy_parent = Lazy::new(|| {
+        parent_evaluator_call_count.set(parent_evaluator_call_count.get() + 1);
+        ("name".to_string(), 25)
+    });
+
+    let lazy_name = lazy_parent.project_ref(|parent| {
+        projection_call_count.set(projection_call_count.get() + 1);
+        parent.0.as_str()
+    });
+    let lazy_age = lazy_parent.project_ref(|parent| parent.1 * 2);
+
+    assert_eq!(parent_evaluator_call_count.get(), 0);
+    assert_eq!(projection_call_count.get(), 0);
+
+    assert_eq!(*lazy_name, "name");
+    assert_eq!(*lazy_name, "name");
+    assert_eq!(*lazy_age, 50);
+
+    assert_eq!(parent_evaluator_call_count.get(), 1);
+    assert_eq!(projection_call_count.get(), 1);
+}
+
+#[test]
+#[allow(unused_variables)]
+fn lazy_project_ref_never_called_if_unused() {
+    let parent_evaluator_call_count = Cell::new(0);
+
+    let lazy_parent = Lazy::new(|| {
+        parent_evaluator_call_count.set(parent_evaluator_call_count.get() + 1);
+        ("name".to_string(), 25)
+    });
+
+    let lazy_age = lazy_parent.project_ref(|parent| parent.1);
+
+    assert_eq!(parent_evaluator_call_count.get(), 0);
+}
+
+#[test]
+fn lazy_preinitialized() {
+    let mut lazy_value = Lazy::preinitialized("warm".to_string());
+
+    assert_eq!(lazy_value.state(), LazyState::Evaluated);
+    assert_eq!(*lazy_value, "warm");
+    assert_eq!(*lazy_value, "warm");
+    assert!(lazy_value.take_evaluator().is_none());
+    assert_eq!(lazy_value.unwrap(), "warm");
+}
+
+#[test]
+fn lazy_unzip() {
+    let evaluator_call_count = Cell::new(0);
+
+    let (lazy_first, lazy_second) = Lazy::new(|| {
+        evaluator_call_count.set(evaluator_call_count.get() + 1);
+        ("first".to_string(), 2)
+    }).unzip();
+
+    assert_eq!(evaluator_call_count.get(), 0);
+
+    assert_eq!(*lazy_second, 2);
+    assert_eq!(*lazy_first, "first");
+    assert_eq!(*lazy_second, 2);
+    assert_eq!(*lazy_first, "first");
+
+    assert_eq!(evaluator_call_count.get(), 1);
+}
+
+#[test]
+fn lazy_unzip_one_half() {
+    let evaluator_call_count = Cell::new(0);
+
+    let (lazy_first, lazy_second) = Lazy::new(|| {
+        evaluator_call_count.set(evaluator_call_count.get() + 1);
+        ("first".to_string(), 2)
+    }).unzip();
+
+    drop(lazy_second);
+
+    assert_eq!(lazy_first.unwrap(), "first");
+    assert_eq!(evaluator_call_count.get(), 1);
+}
+
+#[test]
+fn lazy_vec_map_indexed() {
+    let evaluator_call_count = Cell::new(0);
+    let mapping_call_count = Cell::new(0);
+
+    let lazy_value = Lazy::new(|| {
+        evaluator_call_count.set(evaluator_call_count.get() + 1);
+        vec!["a", "b", "c"]
+    }).map_indexed(|index, value| {
+        mapping_call_count.set(mapping_call_count.get() + 1);
+        format!("{}{}", value, index)
+    });
+
+    assert_eq!(evaluator_call_count.get(), 0);
+    assert_eq!(mapping_call_count.get(), 0);
+
+    assert_eq!(*lazy_value, vec!["a0", "b1", "c2"]);
+    assert_eq!(*lazy_value, vec!["a0", "b1", "c2"]);
+
+    assert_eq!(evaluator_call_count.get(), 1);
+    assert_eq!(mapping_call_count.get(), 3);
+}
+
+#[test]
+fn lazy_option_coalesce_some() {
+    let evaluator_call_count = Cell::new(0);
+    let fallback_call_count = Cell::new(0);
+
+    let lazy_value = Lazy::new(|| {
+        evaluator_call_count.set(evaluator_call_count.get() + 1);
+        Some(10)
+    }).coalesce(|| {
+        fallback_call_count.set(fallback_call_count.get() + 1);
+        20
+    });
+
+    assert_eq!(evaluator_call_count.get(), 0);
+
+    assert_eq!(*lazy_value, 10);
+    assert_eq!(*lazy_value, 10);
+
+    assert_eq!(evaluator_call_count.get(), 1);
+    assert_eq!(fallback_call_count.get(), 0);
+}
+
+#[test]
+fn lazy_option_coalesce_none() {
+    let evaluator_call_count = Cell::new(0);
+    let fallback_call_count = Cell::new(0);
+
+    let lazy_value = Lazy::new(|| {
+        evaluator_call_count.set(evaluator_call_count.get() + 1);
+        None
+    }).coalesce(|| {
+        fallback_call_count.set(fallback_call_count.get() + 1);
+        20
+    });
+
+    assert_eq!(fallback_call_count.get(), 0);
+
+    assert_eq!(*lazy_value, 20);
+    assert_eq!(*lazy_value, 20);
+
+    assert_eq!(evaluator_call_count.get(), 1);
+    assert_eq!(fallback_call_count.get(), 1);
+}
+
+#[test]
+fn borrow_disjoint_mut_fields() {
+    struct Pair<EvalFirst, EvalSecond>
+        where EvalFirst:  FnOnce() -> Vec<i32>,
+              EvalSecond: FnOnce() -> String
+    {
+        first:  Lazy<Vec<i32>, EvalFirst>,
+        second: Lazy<String, EvalSecond>
+    }
+
+    let first_evaluator_call_count = Cell::new(0);
+    let second_evaluator_call_count = Cell::new(0);
+
+    let mut pair = Pair{
+        first: Lazy::new(|| {
+            first_evaluator_call_count.set(first_evaluator_call_count.get() + 1);
+            vec![1, 2]
+        }),
+        second: Lazy::new(|| {
+            second_evaluator_call_count.set(second_evaluator_call_count.get() + 1);
+            "length: ".to_string()
+        })
+    };
+
+    {
+        let (first, second) = borrow_disjoint_mut!(pair.first, pair.second);
+
+        first.push(3);
+        second.push_str(&first.len().to_string());
+    }
+
+    let pair_ref = &mut pair;
+    let (second, first) = borrow_disjoint_mut!(pair_ref.second, pair_ref.first);
+
+    first.clear();
+    second.push('!');
+
+    assert!(pair.first.is_empty());
+    assert_eq!(*pair.second, "length: 3!");
+    assert_eq!(first_evaluator_call_count.get(), 1);
+    assert_eq!(second_evaluator_call_count.get(), 1);
+}
+
+#[test]
+fn shared_lazy_downgrade() {
+    let lazy_value = SharedLazy::new(|| "shared string".to_string());
+    let lazy_value_clone = lazy_value.clone();
+
+    let weak_lazy_value = SharedLazy::downgrade(&lazy_value);
+
+    assert_eq!(lazy_value.len(), 13);
+
+    drop(lazy_value);
+
+    let upgraded_lazy_value = weak_lazy_value.upgrade().expect("weak handle must upgrade while a strong handle lives");
+
+    assert_eq!(*upgraded_lazy_value, "shared string");
+
+    drop(upgraded_lazy_value);
+    drop(lazy_value_clone);
+
+    assert!(weak_lazy_value.upgrade().is_none());
+    assert!(weak_lazy_value.clone().upgrade().is_none());
+}
+
+#[test]
+fn shared_lazy_downgrade_drops_value() {
+    let drop_count = Rc::new(Cell::new(0));
+
+    let weak_lazy_value = {
+        let drop_count = Rc::clone(&drop_count);
+        let lazy_value = SharedLazy::new(move || SomethingRcDropCounting{drop_count});
+
+        let _ = &*lazy_value;
+
+        SharedLazy::downgrade(&lazy_value)
+    };
+
+    assert_eq!(drop_count.get(), 1);
+    assert!(weak_lazy_value.upgrade().is_none());
+}
+
+#[test]
+fn lazy_map_freeze() {
+    let mut evaluator_call_count = 0;
+
+    let frozen_value = Lazy::new(|| {
+        evaluator_call_count += 1;
+        vec![1, 2, 3]
+    }).map_freeze(|values| values.iter().sum::<i32>());
+
+    assert_eq!(evaluator_call_count, 1);
+    assert_eq!(*frozen_value, 6);
+    assert_eq!(*frozen_value.as_ref(), 6);
+    assert_eq!(frozen_value.into_inner(), 6);
+}
+
+#[test]
+fn lazy_map_freeze_if_evaluated() {
+    let mut evaluator_call_count = 0;
+
+    let mut lazy_value = Lazy::new(|| {
+        evaluator_call_count += 1;
+        "frozen".to_string()
+    });
+
+    lazy_value.push('!');
+
+    let frozen_value = lazy_value.map_freeze(|string| string.to_uppercase());
+
+    assert_eq!(*frozen_value, "FROZEN!");
+    assert_eq!(evaluator_call_count, 1);
+}
+
+#[test]
+fn lazy_new_validated_valid() {
+    let evaluator_call_count = Cell::new(0);
+    let validator_call_count = Cell::new(0);
+
+    let lazy_value = Lazy::new_validated(
+        || {
+            evaluator_call_count.set(evaluator_call_count.get() + 1);
+            8080
+        },
+        |port| {
+            validator_call_count.set(validator_call_count.get() + 1);
+            *port > 1024
+        }
+    );
+
+    assert_eq!(evaluator_call_count.get(), 0);
+
+    assert_eq!(*lazy_value, Ok(8080));
+    assert_eq!(*lazy_value, Ok(8080));
+
+    assert_eq!(evaluator_call_count.get(), 1);
+    assert_eq!(validator_call_count.get(), 1);
+}
+
+#[test]
+fn lazy_new_validated_invalid() {
+    let evaluator_call_count = Cell::new(0);
+    let validator_call_count = Cell::new(0);
+
+    let lazy_value = Lazy::new_validated(
+        || {
+            evaluator_call_count.set(evaluator_call_count.get() + 1);
+            80
+        },
+        |port| {
+            validator_call_count.set(validator_call_count.get() + 1);
+            *port > 1024
+        }
+    );
+
+    assert_eq!(*lazy_value, Err(ValidationError));
+    assert_eq!(*lazy_value, Err(ValidationError));
+
+    assert_eq!(evaluator_call_count.get(), 1);
+    assert_eq!(validator_call_count.get(), 1);
+}
+
+#[test]
+fn lazy_vec_sum_product() {
+    let mut evaluator_call_count = 0;
+
+    let lazy_value = Lazy::new(|| {
+        evaluator_call_count += 1;
+        vec![2, 3, 4]
+    });
+
+    assert_eq!(lazy_value.sum(), 9);
+    assert_eq!(lazy_value.product(), 24);
+    assert_eq!(lazy_value.sum(), 9);
+    assert_eq!(evaluator_call_count, 1);
+}
+
+#[test]
+fn lazy_vec_sum_product_empty() {
+    let lazy_value = Lazy::new(Vec::<f64>::new);
+
+    assert_eq!(lazy_value.sum(), 0.0);
+    assert_eq!(lazy_value.product(), 1.0);
+}
+
+#[test]
+fn lazy_try_get_mut() {
+    let mut evaluator_call_count = 0;
+
+    let mut lazy_value = Lazy::new(|| {
+        evaluator_call_count += 1;
+        vec![1, 2]
+    });
+
+    assert!(lazy_value.try_get_mut().is_none());
+    assert!(lazy_value.try_get_mut().is_none());
+    assert_eq!(lazy_value.state(), LazyState::Unevaluated);
+
+    assert_eq!(lazy_value.len(), 2);
+
+    lazy_value.try_get_mut().expect("value must be evaluated").push(3);
+
+    assert_eq!(*lazy_value, vec![1, 2, 3]);
+    assert_eq!(evaluator_call_count, 1);
+}
+
+#[test]
+fn lazy_apply() {
+    let mut evaluator_call_count = 0;
+
+    let mut lazy_value = Lazy::new(|| {
+        evaluator_call_count += 1;
+        vec![3, 1, 2]
+    });
+
+    lazy_value.apply(|values| values.push(0));
+    lazy_value.apply(|values| values.sort()).push(4);
+
+    assert_eq!(*lazy_value, vec![0, 1, 2, 3, 4]);
+    assert_eq!(evaluator_call_count, 1);
+}
+
+#[test]
+fn seeded_lazy_seed_ref_then_generate() {
+    let generator_call_count = Cell::new(0);
+
+    let lazy_value = Lazy::new_seeded(vec![3, 1, 2], |mut seed: Vec<i32>| {
+        generator_call_count.set(generator_call_count.get() + 1);
+        seed.sort();
+        seed
+    });
+
+    assert_eq!(*lazy_value.seed_ref().expect("seed must be pending"), vec![3, 1, 2]);
+    assert_eq!(lazy_value.seed_ref().expect("seed must be pending").len(), 3);
+    assert_eq!(generator_call_count.get(), 0);
+
+    assert_eq!(*lazy_value, vec![1, 2, 3]);
+    assert_eq!(*lazy_value.as_ref(), vec![1, 2, 3]);
+
+    assert!(lazy_value.seed_ref().is_none());
+    assert_eq!(generator_call_count.get(), 1);
+}
+
+#[test]
+#[should_panic]
+fn seeded_lazy_deref_while_seed_borrowed() {
+    let lazy_value = SeededLazy::new(5, |seed| seed * 2);
+
+    let _seed_ref = lazy_value.seed_ref();
+
+    let _ = *lazy_value;
+}
+
+#[test]
+fn lazy_vec_result_collapse_ok() {
+    let evaluator_call_count = Cell::new(0);
+
+    let lazy_value = Lazy::new(|| -> Vec<Result<i32, String>> {
+        evaluator_call_count.set(evaluator_call_count.get() + 1);
+        vec![Ok(1), Ok(2), Ok(3)]
+    }).collapse();
+
+    assert_eq!(evaluator_call_count.get(), 0);
+
+    assert_eq!(*lazy_value, Ok(vec![1, 2, 3]));
+    assert_eq!(*lazy_value, Ok(vec![1, 2, 3]));
+    assert_eq!(evaluator_call_count.get(), 1);
+}
+
+#[test]
+fn lazy_vec_result_collapse_first_err() {
+    let evaluator_call_count = Cell::new(0);
+
+    let lazy_value = Lazy::new(|| {
+        evaluator_call_count.set(evaluator_call_count.get() + 1);
+        vec![Ok(1), Err("second"), Ok(3), Err("fourth")]
+    }).collapse();
+
+    assert_eq!(evaluator_call_count.get(), 0);
+
+    assert_eq!(*lazy_value, Err("second"));
+    assert_eq!(evaluator_call_count.get(), 1);
+}
+
+#[test]
+fn lazy_map_or_force() {
+    let mut evaluator_call_count = 0;
+
+    let lazy_value = Lazy::new(|| {
+        evaluator_call_count += 1;
+        "value".to_string()
+    });
+
+    assert_eq!(lazy_value.map_or_force(|value| value.len()), (5, true));
+    assert_eq!(lazy_value.map_or_force(|value| value.len()), (5, false));
+    assert_eq!(lazy_value.map_or_force(|value| value.to_uppercase()), ("VALUE".to_string(), false));
+    assert_eq!(evaluator_call_count, 1);
+}
+
+#[test]
+fn lazy_cmp_if_evaluated() {
+    fn get_lesser_value() -> i32 { 1 }
+    fn get_greater_value() -> i32 { 2 }
+
+    let lesser_lazy_value: Lazy<i32, fn() -> i32> = Lazy::new(get_lesser_value);
+    let greater_lazy_value: Lazy<i32, fn() -> i32> = Lazy::new(get_greater_value);
+
+    assert_eq!(lesser_lazy_value.cmp_if_evaluated(&greater_lazy_value), None);
+    assert_eq!(greater_lazy_value.cmp_if_evaluated(&lesser_lazy_value), None);
+    assert_eq!(lesser_lazy_value.state(), LazyState::Unevaluated);
+    assert_eq!(greater_lazy_value.state(), LazyState::Unevaluated);
+
+    assert_eq!(*lesser_lazy_value, 1);
+
+    assert_eq!(lesser_lazy_value.cmp_if_evaluated(&greater_lazy_value), None);
+    assert_eq!(greater_lazy_value.cmp_if_evaluated(&lesser_lazy_value), None);
+    assert_eq!(greater_lazy_value.state(), LazyState::Unevaluated);
+
+    assert_eq!(*greater_lazy_value, 2);
+
+    assert_eq!(lesser_lazy_value.cmp_if_evaluated(&greater_lazy_value), Some(cmp::Ordering::Less));
+    assert_eq!(greater_lazy_value.cmp_if_evaluated(&lesser_lazy_value), Some(cmp::Ordering::Greater));
+    assert_eq!(lesser_lazy_value.cmp_if_evaluated(&lesser_lazy_value), Some(cmp::Ordering::Equal));
+}
+
+#[test]
+fn lazy_cmp_if_evaluated_only_other() {
+    fn get_value() -> i32 { 1 }
+
+    let lazy_value: Lazy<i32, fn() -> i32> = Lazy::new(get_value);
+    let other_lazy_value: Lazy<i32, fn() -> i32> = Lazy::new(get_value);
+
+    assert_eq!(*other_lazy_value, 1);
+
+    assert_eq!(lazy_value.cmp_if_evaluated(&other_lazy_value), None);
+    assert_eq!(lazy_value.state(), LazyState::Unevaluated);
+}
+
+#[test]
+fn lazy_from_reader() {
+    let parse_call_count = Cell::new(0);
+
+    let lazy_value = Lazy::from_reader(&b"12 34 56"[..], |mut reader| {
+        parse_call_count.set(parse_call_count.get() + 1);
+
+        let mut contents = String::new();
+        reader.read_to_string(&mut contents).expect("reading from a byte slice must succeed");
+
+        contents.split_whitespace()
+            .map(|number| number.parse::<i32>().expect("number must be valid"))
+            .collect::<Vec<_>>()
+    });
+
+    assert_eq!(parse_call_count.get(), 0);
+
+    assert_eq!(*lazy_value, vec![12, 34, 56]);
+    assert_eq!(*lazy_value, vec![12, 34, 56]);
+    assert_eq!(parse_call_count.get(), 1);
+}
+
+#[test]
+fn lazy_guard_mutates_value() {
+    let mut evaluator_called_times = 0;
+    let mut lazy_vec = Lazy::new(|| {
+        evaluator_called_times += 1;
+        vec![1, 2, 3]
+    });
+
+    {
+        let mut guard = lazy_vec.guard();
+
+        guard.push(4);
+        guard[0] = 10;
+    }
+
+    assert_eq!(*lazy_vec, vec![10, 2, 3, 4]);
+    assert_eq!(evaluator_called_times, 1);
+}
+
+#[test]
+fn lazy_guard_on_drop_called_once() {
+    let on_drop_called_times = Cell::new(0);
+    let mut lazy_value = Lazy::new(|| 2);
+
+    {
+        let mut guard = lazy_value.guard().on_drop(|value| {
+            on_drop_called_times.set(on_drop_called_times.get() + 1);
+            *value *= 10;
+        });
+
+        *guard += 1;
+
+        assert_eq!(on_drop_called_times.get(), 0);
+    }
+
+    assert_eq!(on_drop_called_times.get(), 1);
+    assert_eq!(*lazy_value, 30);
+    assert_eq!(on_drop_called_times.get(), 1);
+}
+
+#[test]
+fn lazy_try_project_ref_ok() {
+    let mut evaluator_called_times = 0;
+    let lazy_map = Lazy::new(|| {
+        evaluator_called_times += 1;
+        vec![("one", 1), ("two", 2)].into_iter().collect::<HashMap<_, _>>()
+    });
+
+    let projected = lazy_map.try_project_ref(|map| map.get("two").ok_or("missing key"));
+
+    assert_eq!(projected, Ok(&2));
+    assert_eq!(lazy_map.try_project_ref(|map| map.get("one").ok_or("missing key")), Ok(&1));
+
+    drop(lazy_map);
+
+    assert_eq!(evaluator_called_times, 1);
+}
+
+#[test]
+fn lazy_try_project_ref_err() {
+    let mut evaluator_called_times = 0;
+    let lazy_map = Lazy::new(|| {
+        evaluator_called_times += 1;
+        vec![("one", 1)].into_iter().collect::<HashMap<_, _>>()
+    });
+
+    let projected = lazy_map.try_project_ref(|map| map.get("three").ok_or("missing key"));
+
+    assert_eq!(projected, Err("missing key"));
+    assert_eq!(*lazy_map.get("one").unwrap(), 1);
+
+    drop(lazy_map);
+
+    assert_eq!(evaluator_called_times, 1);
+}
+
+#[test]
+fn ttl_lazy_reuses_value_before_expiry() {
+    let evaluator_called_times = Cell::new(0);
+    let mut lazy_value = TtlLazy::new(|| {
+        evaluator_called_times.set(evaluator_called_times.get() + 1);
+        evaluator_called_times.get()
+    }, Duration::from_secs(3600));
+
+    assert!(lazy_value.is_expired());
+    assert_eq!(evaluator_called_times.get(), 0);
+
+    assert_eq!(*lazy_value.get(), 1);
+    assert!(!lazy_value.is_expired());
+
+    *lazy_value.get_mut() += 10;
+
+    assert_eq!(*lazy_value.get(), 11);
+    assert_eq!(evaluator_called_times.get(), 1);
+}
+
+#[test]
+fn ttl_lazy_recomputes_after_expiry() {
+    let evaluator_called_times = Cell::new(0);
+    let mut lazy_value = TtlLazy::new(|| {
+        evaluator_called_times.set(evaluator_called_times.get() + 1);
+        evaluator_called_times.get()
+    }, Duration::from_secs(0));
+
+    assert_eq!(*lazy_value.get(), 1);
+    assert!(lazy_value.is_expired());
+    assert_eq!(*lazy_value.get(), 2);
+    assert_eq!(*lazy_value.get_mut(), 3);
+    assert_eq!(lazy_value.time_to_live(), Duration::from_secs(0));
+    assert_eq!(evaluator_called_times.get(), 3);
+}
+
+#[test]
+fn ttl_lazy_with_mock_clock() {
+    let clock = MockClock::new();
+    let evaluator_called_times = Cell::new(0);
+    let mut lazy_value = TtlLazy::with_clock(|| {
+        evaluator_called_times.set(evaluator_called_times.get() + 1);
+        evaluator_called_times.get()
+    }, Duration::from_secs(10), &clock);
+
+    assert_eq!(*lazy_value.get(), 1);
+
+    clock.advance(Duration::from_secs(9));
+
+    assert!(!lazy_value.is_expired());
+    assert_eq!(*lazy_value.get(), 1);
+
+    clock.advance(Duration::from_secs(1));
+
+    assert!(lazy_value.is_expired());
+    assert_eq!(*lazy_value.get(), 2);
+
+    clock.advance(Duration::from_secs(5));
+
+    assert_eq!(*lazy_value.get(), 2);
+    assert_eq!(evaluator_called_times.get(), 2);
+}
+
+#[test]
+fn ttl_lazy_shared_mock_clock() {
+    let clock = Rc::new(MockClock::new());
+    let mut short_lived = TtlLazy::with_clock(|| "short", Duration::from_secs(1), Rc::clone(&clock));
+    let mut long_lived = TtlLazy::with_clock(|| "long", Duration::from_secs(60), Rc::clone(&clock));
+
+    short_lived.get();
+    long_lived.get();
+
+    clock.advance(Duration::from_secs(30));
+
+    assert!(short_lived.is_expired());
+    assert!(!long_lived.is_expired());
+}
+
+#[test]
+fn lazy_force_then() {
+    let mut evaluator_called_times = 0;
+    let lazy_string = Lazy::new(|| {
+        evaluator_called_times += 1;
+        String::from("moo")
+    });
+
+    assert_eq!(lazy_string.force_then(|string| string.len()), 3);
+    assert_eq!(lazy_string.force_then(|string| string.to_uppercase()), "MOO");
+    assert_eq!(*lazy_string, "moo");
+
+    drop(lazy_string);
+
+    assert_eq!(evaluator_called_times, 1);
+}
+
+#[test]
+fn lazy_vec_extend_into() {
+    let mut evaluator_called_times = 0;
+    let lazy_vec = Lazy::new(|| {
+        evaluator_called_times += 1;
+        vec![3, 4, 5]
+    });
+
+    let mut target = vec![1, 2];
+
+    lazy_vec.extend_into(&mut target);
+
+    assert_eq!(target, vec![1, 2, 3, 4, 5]);
+    assert_eq!(evaluator_called_times, 1);
+}
+
+#[test]
+fn lazy_vec_extend_into_other_collection() {
+    let lazy_pairs = Lazy::new(|| vec![("two", 2), ("one", 10)]);
+
+    let mut target: HashMap<_, _> = vec![("one", 1)].into_iter().collect();
+
+    lazy_pairs.extend_into(&mut target);
+
+    assert_eq!(target.len(), 2);
+    assert_eq!(target["one"], 10);
+    assert_eq!(target["two"], 2);
+}
+
+#[test]
+fn lazy_drop_order_value_before_evaluator_template() {
+    let drop_log = RefCell::new(Vec::new());
+
+    let evaluator_probe = SomethingDropLogging{name: "evaluator", drop_log: &drop_log};
+    let lazy_value = Lazy::new_reusable(move || {
+        SomethingDropLogging{name: "value", drop_log: evaluator_probe.drop_log}
+    });
+
+    lazy_value.as_ref();
+
+    assert_eq!(*drop_log.borrow(), vec!["evaluator"]);
+
+    drop_log.borrow_mut().clear();
+    drop(lazy_value);
+
+    assert_eq!(*drop_log.borrow(), vec!["value", "evaluator"]);
+}
+
+#[test]
+fn lazy_drop_order_evaluator_consumed_before_value() {
+    let drop_log = RefCell::new(Vec::new());
+
+    let evaluator_probe = SomethingDropLogging{name: "evaluator", drop_log: &drop_log};
+    let lazy_value = Lazy::new(move || {
+        SomethingDropLogging{name: "value", drop_log: evaluator_probe.drop_log}
+    });
+
+    lazy_value.as_ref();
+    drop(lazy_value);
+
+    assert_eq!(*drop_log.borrow(), vec!["evaluator", "value"]);
+}
+
+#[test]
+fn lazy_drop_unevaluated_drops_evaluator_once() {
+    let evaluator_drop_count = Cell::new(0);
+    let value_drop_count = Cell::new(0);
+
+    let evaluator_probe = SomethingDropCounting{drop_count: &evaluator_drop_count};
+    let lazy_value = Lazy::new(|| {
+        let _evaluator_probe = evaluator_probe;
+        SomethingDropCounting{drop_count: &value_drop_count}
+    });
+
+    drop(lazy_value);
+
+    assert_eq!(evaluator_drop_count.get(), 1);
+    assert_eq!(value_drop_count.get(), 0);
+}
+
+#[test]
+fn lazy_drop_evaluated_drops_evaluator_and_value_once() {
+    let evaluator_drop_count = Cell::new(0);
+    let value_drop_count = Cell::new(0);
+
+    let evaluator_probe = SomethingDropCounting{drop_count: &evaluator_drop_count};
+    let lazy_value = Lazy::new(|| {
+        let _evaluator_probe = evaluator_probe;
+        SomethingDropCounting{drop_count: &value_drop_count}
+    });
+
+    lazy_value.as_ref();
+
+    assert_eq!(evaluator_drop_count.get(), 1);
+    assert_eq!(value_drop_count.get(), 0);
+
+    drop(lazy_value);
+
+    assert_eq!(evaluator_drop_count.get(), 1);
+    assert_eq!(value_drop_count.get(), 1);
+}
+
+#[test]
+fn lazy_drop_unwrapped_value_not_dropped_by_lazy() {
+    let evaluator_drop_count = Cell::new(0);
+    let value_drop_count = Cell::new(0);
+
+    let evaluator_probe = SomethingDropCounting{drop_count: &evaluator_drop_count};
+    let lazy_value = Lazy::new(|| {
+        let _evaluator_probe = evaluator_probe;
+        SomethingDropCounting{drop_count: &value_drop_count}
+    });
+
+    let value = lazy_value.unwrap();
+
+    assert_eq!(evaluator_drop_count.get(), 1);
+    assert_eq!(value_drop_count.get(), 0);
+
+    drop(value);
+
+    assert_eq!(evaluator_drop_count.get(), 1);
+    assert_eq!(value_drop_count.get(), 1);
+}
+
+#[test]
+fn lazy_drop_taken_evaluator_not_dropped_by_lazy() {
+    let evaluator_drop_count = Cell::new(0);
+
+    let evaluator_probe = SomethingDropCounting{drop_count: &evaluator_drop_count};
+    let mut lazy_value = Lazy::new(move || {
+        let _evaluator_probe = evaluator_probe;
+        0
+    });
+
+    let evaluator = lazy_value.take_evaluator();
+
+    drop(lazy_value);
+
+    assert_eq!(evaluator_drop_count.get(), 0);
+
+    drop(evaluator);
+
+    assert_eq!(evaluator_drop_count.get(), 1);
+}
+
+#[test]
+fn lazy_project_to_owned() {
+    struct Person {
+        name: String,
+        tags: Vec<u32>
+    }
+
+    let mut evaluator_called_times = 0;
+    let lazy_person = Lazy::new(|| {
+        evaluator_called_times += 1;
+        Person{name: String::from("Ferris Crab"), tags: vec![1, 2, 3]}
+    });
+
+    let first_name: String = lazy_person.project_to_owned(|person| &person.name[..6]);
+    let tags: Vec<u32> = lazy_person.project_to_owned(|person| &person.tags[1..]);
+
+    assert_eq!(first_name, "Ferris");
+    assert_eq!(tags, vec![2, 3]);
+
+    drop(lazy_person);
+
+    assert_eq!(evaluator_called_times, 1);
+}
+
+#[test]
+fn force_map_collect_preserves_order() {
+    let evaluation_order = RefCell::new(Vec::new());
+
+    let lazies: Vec<_> = (0..4)
+        .map(|index| {
+            let evaluation_order = &evaluation_order;
+            Lazy::new(move || {
+                evaluation_order.borrow_mut().push(index);
+                index * 2
+            })
+        })
+        .collect();
+
+    assert!(evaluation_order.borrow().is_empty());
+
+    let mapped = force_map_collect(lazies, |value| value.to_string());
+
+    assert_eq!(mapped, vec!["0", "2", "4", "6"]);
+    assert_eq!(*evaluation_order.borrow(), vec![0, 1, 2, 3]);
+}
+
+#[test]
+fn force_map_collect_evaluated_lazies() {
+    let evaluator_called_times = Cell::new(0);
+
+    let lazies: Vec<_> = (1..=3)
+        .map(|value| {
+            let evaluator_called_times = &evaluator_called_times;
+            Lazy::new(move || {
+                evaluator_called_times.set(evaluator_called_times.get() + 1);
+                value
+            })
+        })
+        .collect();
+
+    assert_eq!(*lazies[1], 2);
+
+    let mapped = force_map_collect(lazies, |value| value + 1);
+
+    assert_eq!(mapped, vec![2, 3, 4]);
+    assert_eq!(evaluator_called_times.get(), 3);
+}
+
+#[test]
+fn lazy_string_as_str() {
+    let mut evaluator_called_times = 0;
+    let lazy_string = Lazy::new(|| {
+        evaluator_called_times += 1;
+        String::from("moo")
+    });
+
+    let string_slice: &str = lazy_string.as_str();
+
+    assert_eq!(string_slice, "moo");
+    assert_eq!(lazy_string.as_str().len(), 3);
+
+    drop(lazy_string);
+
+    assert_eq!(evaluator_called_times, 1);
+}
+
+#[test]
+fn lazy_str_as_str() {
+    let mut evaluator_called_times = 0;
+    let lazy_str = Lazy::new(|| {
+        evaluator_called_times += 1;
+        "moo"
+    });
+
+    let string_slice: &'static str = lazy_str.as_str();
+
+    assert_eq!(string_slice, "moo");
+    assert_eq!(lazy_str.as_str(), "moo");
+    assert_eq!(evaluator_called_times, 1);
+}
+
+#[test]
+fn lazy_force_with_limit_disallowed() {
+    let mut evaluator_called_times = 0;
+    let lazy_value = Lazy::new(|| {
+        evaluator_called_times += 1;
+        42
+    });
+
+    assert_eq!(lazy_value.force_with_limit(|| false), None);
+    assert_eq!(lazy_value.state(), LazyState::Unevaluated);
+
+    assert_eq!(evaluator_called_times, 0);
+}
+
+#[test]
+fn lazy_force_with_limit_allowed() {
+    let allow_called_times = Cell::new(0);
+    let allow = || {
+        allow_called_times.set(allow_called_times.get() + 1);
+        true
+    };
+
+    let mut evaluator_called_times = 0;
+    let lazy_value = Lazy::new(|| {
+        evaluator_called_times += 1;
+        42
+    });
+
+    assert_eq!(lazy_value.force_with_limit(allow), Some(&42));
+    assert_eq!(lazy_value.force_with_limit(allow), Some(&42));
+    assert_eq!(lazy_value.force_with_limit(|| false), Some(&42));
+    assert_eq!(allow_called_times.get(), 1);
+
+    assert_eq!(evaluator_called_times, 1);
+}
+
+#[test]
+fn lazy_merge() {
+    let first_called_times = Cell::new(0);
+    let second_called_times = Cell::new(0);
+
+    let lazy_defaults = Lazy::new(|| {
+        first_called_times.set(first_called_times.get() + 1);
+        vec![("port", 80), ("timeout", 30)].into_iter().collect::<HashMap<_, _>>()
+    });
+    let lazy_overrides = Lazy::new(|| {
+        second_called_times.set(second_called_times.get() + 1);
+        vec![("port", 8080)].into_iter().collect::<HashMap<_, _>>()
+    });
+
+    let lazy_config = lazy_defaults.merge(lazy_overrides, |mut defaults, overrides| {
+        defaults.extend(overrides);
+        defaults
+    });
+
+    assert_eq!(first_called_times.get(), 0);
+    assert_eq!(second_called_times.get(), 0);
+
+    assert_eq!(lazy_config["port"], 8080);
+    assert_eq!(lazy_config["timeout"], 30);
+    assert_eq!(first_called_times.get(), 1);
+    assert_eq!(second_called_times.get(), 1);
+}
+
+#[test]
+fn lazy_on_eval_notify_evaluated() {
+    let (lazy_value, receiver) = Lazy::on_eval_notify(|| 42);
+
+    assert!(receiver.try_recv().is_err());
+
+    assert_eq!(*lazy_value, 42);
+    assert_eq!(*lazy_value, 42);
+
+    assert_eq!(receiver.try_recv(), Ok(()));
+    assert_eq!(receiver.try_recv(), Err(mpsc::TryRecvError::Disconnected));
+}
+
+#[test]
+fn lazy_on_eval_notify_never_evaluated() {
+    let (lazy_value, receiver) = Lazy::on_eval_notify(|| 42);
+
+    assert_eq!(receiver.try_recv(), Err(mpsc::TryRecvError::Empty));
+
+    drop(lazy_value);
+
+    assert_eq!(receiver.recv(), Err(mpsc::RecvError));
+}
+
+#[test]
+fn lazy_on_eval_notify_other_thread() {
+    let (lazy_value, receiver) = Lazy::on_eval_notify(|| String::from("moo"));
+
+    let handle = thread::spawn(move || receiver.recv().is_ok());
+
+    assert_eq!(lazy_value.len(), 3);
+    assert!(handle.join().unwrap());
+}
+
+#[test]
+fn scoped_force_all_borrowed_data() {
+    let evaluator_called_times = AtomicUsize::new(0);
+    let multipliers = [2, 3, 4];
+
+    let mut lazies: Vec<_> = multipliers.iter()
+        .map(|multiplier| {
+            let evaluator_called_times = &evaluator_called_times;
+            Lazy::new(move || {
+                evaluator_called_times.fetch_add(1, Ordering::SeqCst);
+                multiplier * 10
+            })
+        })
+        .collect();
+
+    assert_eq!(*lazies[0], 20);
+
+    scoped_force_all(&mut lazies);
+
+    assert!(lazies.iter().all(|lazy| lazy.state() == LazyState::Evaluated));
+    assert_eq!(lazies.iter().map(|lazy| **lazy).collect::<Vec<_>>(), vec![20, 30, 40]);
+    assert_eq!(evaluator_called_times.load(Ordering::SeqCst), 3);
+}
+
+#[test]
+fn counting_lazy_access_count() {
+    let mut evaluator_called_times = 0;
+    let mut lazy_value = Lazy::new_counting(|| {
+        evaluator_called_times += 1;
+        vec![1, 2, 3]
+    });
+
+    assert_eq!(lazy_value.access_count(), 0);
+
+    assert_eq!(lazy_value.len(), 3);
+    lazy_value.push(4);
+    assert_eq!(lazy_value.as_ref(), &vec![1, 2, 3, 4]);
+    lazy_value.as_mut().clear();
+
+    assert_eq!(lazy_value.access_count(), 4);
+
+    let lazy_value = lazy_value.into_lazy();
+
+    assert!(lazy_value.is_empty());
+
+    drop(lazy_value);
+
+    assert_eq!(evaluator_called_times, 1);
+}
+
+#[test]
+fn lazy_is_evaluated() {
+    let mut evaluator_called_times = 0;
+    let lazy_value = Lazy::new(|| {
+        evaluator_called_times += 1;
+        42
+    });
+
+    assert!(!lazy_value.is_evaluated());
+    assert!(!lazy_value.is_evaluated());
+
+    assert_eq!(*lazy_value, 42);
+
+    assert!(lazy_value.is_evaluated());
+    assert_eq!(evaluator_called_times, 1);
+}
+
+#[test]
+fn lazy_is_evaluated_while_borrowed() {
+    let lazy_value = Lazy::new(|| 42);
+
+    let value_ref = lazy_value.value_ref();
+
+    assert!(lazy_value.is_evaluated());
+    assert_eq!(*value_ref, 42);
+}
+
+#[test]
+fn lazy_unwrap_ok() {
+    let mut evaluator_called_times = 0;
+    let lazy_result: Lazy<Result<i32, String>, _> = Lazy::new(|| {
+        evaluator_called_times += 1;
+        "42".parse().map_err(|_| String::from("not a number"))
+    });
+
+    let lazy_value = lazy_result.unwrap_ok();
+
+    assert_eq!(*lazy_value, 42);
+    assert_eq!(*lazy_value, 42);
+
+    drop(lazy_value);
+
+    assert_eq!(evaluator_called_times, 1);
+}
+
+#[test]
+#[should_panic(expected = "not a number")]
+fn lazy_unwrap_ok_err() {
+    let lazy_result: Lazy<Result<i32, String>, _> = Lazy::new(|| {
+        "forty-two".parse().map_err(|_| String::from("not a number"))
+    });
+
+    let lazy_value = lazy_result.unwrap_ok();
+
+    let _value = *lazy_value;
+}
+
+#[test]
+fn lazy_peek_unevaluated() {
+    let evaluator_called_times = Cell::new(0);
+    let lazy_value = Lazy::new(|| {
+        evaluator_called_times.set(evaluator_called_times.get() + 1);
+        42
+    });
+
+    for _ in 0..3 {
+        assert_eq!(lazy_value.peek(), None);
+    }
+
+    assert_eq!(evaluator_called_times.get(), 0);
+}
+
+#[test]
+fn lazy_peek_evaluated() {
+    let mut evaluator_called_times = 0;
+    let mut lazy_value = Lazy::new(|| {
+        evaluator_called_times += 1;
+        42
+    });
+
+    *lazy_value += 1;
+
+    assert_eq!(lazy_value.peek(), Some(&43));
+    assert_eq!(lazy_value.peek(), Some(&43));
+    assert_eq!(evaluator_called_times, 1);
+}
+
+#[test]
+fn lazy_pipe() {
+    let evaluator_called_times = Cell::new(0);
+
+    let lazy_sum = Lazy::new(|| {
+            evaluator_called_times.set(evaluator_called_times.get() + 1);
+            "1,2,3"
+        })
+        .pipe(|lazy_line| Lazy::new(move || {
+            lazy_line.unwrap()
+                .split(',')
+                .map(|number| number.parse().map_err(|_| number.to_string()))
+                .collect::<Vec<Result<i32, String>>>()
+        }))
+        .pipe(|lazy_results| lazy_results.collapse())
+        .pipe(Lazy::unwrap_ok)
+        .pipe(|lazy_numbers| lazy_numbers.map_indexed(|index, number| index as i32 * number));
+
+    assert_eq!(evaluator_called_times.get(), 0);
+
+    assert_eq!(*lazy_sum, vec![0, 2, 6]);
+    assert_eq!(evaluator_called_times.get(), 1);
+}
+
+#[test]
+fn lazy_force() {
+    let mut evaluator_called_times = 0;
+    let lazy_value = Lazy::new(|| {
+        evaluator_called_times += 1;
+        String::from("moo")
+    });
+
+    assert_eq!(lazy_value.force(), "moo");
+    assert_eq!(lazy_value.force(), "moo");
+    assert!(lazy_value.is_evaluated());
+    assert_eq!(lazy_value.force().len(), 3);
+
+    drop(lazy_value);
+
+    assert_eq!(evaluator_called_times, 1);
+}
+
+#[test]
+fn lazy_static_str_ref() {
+    static WORDS: [&str; 3] = ["lorem", "ipsum", "dolor"];
+
+    let mut evaluator_called_times = 0;
+    let lazy_word = Lazy::new(|| {
+        evaluator_called_times += 1;
+        WORDS.iter().copied().find(|word| word.starts_with('i')).unwrap_or_default()
+    });
+
+    let word: &'static str = *lazy_word;
+
+    assert_eq!(word, "ipsum");
+    assert_eq!(lazy_word.len(), 5);
+    assert_eq!(evaluator_called_times, 1);
+}
+
+#[test]
+fn lazy_static_slice_ref() {
+    static NUMBERS: [i32; 5] = [1, 2, 3, 4, 5];
+
+    let evaluator_called_times = Cell::new(0);
+    let lazy_tail: Lazy<&'static [i32], _> = Lazy::new(|| {
+        evaluator_called_times.set(evaluator_called_times.get() + 1);
+        &NUMBERS[2..]
+    });
+
+    assert_eq!(evaluator_called_times.get(), 0);
+    assert_eq!(*lazy_tail, &[3, 4, 5]);
+    assert_eq!(lazy_tail.unwrap().len(), 3);
+    assert_eq!(evaluator_called_times.get(), 1);
+}
+
+#[test]
+fn lazy_debug_unevaluated() {
+    let evaluator_called_times = Cell::new(0);
+    let lazy_value = Lazy::new(|| {
+        evaluator_called_times.set(evaluator_called_times.get() + 1);
+        vec![1, 2]
+    });
+
+    assert_eq!(format!("{:?}", lazy_value), "Lazy { <unevaluated> }");
+    assert_eq!(evaluator_called_times.get(), 0);
+}
+
+#[test]
+fn lazy_debug_evaluated() {
+    let lazy_value = Lazy::new(|| vec![1, 2]);
+
+    lazy_value.force();
+
+    assert_eq!(format!("{:?}", lazy_value), "Lazy { evaluated: [1, 2] }");
+}
+
+#[test]
+fn lazy_debug_derived_for_containing_struct() {
+    #[derive(Debug)]
+    struct Config {
+        name: Lazy<String, fn() -> String>
+    }
+
+    let config = Config{name: Lazy::new(|| String::from("moo"))};
+
+    assert_eq!(format!("{:?}", config), "Config { name: Lazy { <unevaluated> } }");
+    assert_eq!(*config.name, "moo");
+    assert_eq!(format!("{:?}", config), "Config { name: Lazy { evaluated: \"moo\" } }");
+}
+
+#[test]
+fn lazy_take_if_evaluated_bulk() {
+    let evaluator_called_times = Cell::new(0);
+
+    let mut lazies: Vec<_> = (0..4)
+        .map(|index| {
+            let evaluator_called_times = &evaluator_called_times;
+            Lazy::new(move || {
+                evaluator_called_times.set(evaluator_called_times.get() + 1);
+                index * 10
+            })
+        })
+        .collect();
+
+    lazies[1].force();
+    lazies[3].force();
+
+    let taken: Vec<_> = lazies.iter_mut()
+        .map(Lazy::take_if_evaluated)
+        .collect();
+
+    assert_eq!(taken, vec![None, Some(10), None, Some(30)]);
+    assert_eq!(evaluator_called_times.get(), 2);
+
+    let states: Vec<_> = lazies.iter().map(Lazy::state).collect();
+
+    assert_eq!(states, vec![LazyState::Unevaluated, LazyState::Unarmed, LazyState::Unevaluated, LazyState::Unarmed]);
+    assert_eq!(*lazies[0], 0);
+    assert_eq!(evaluator_called_times.get(), 3);
+}
+
+#[test]
+fn lazy_take_if_evaluated_rearm() {
+    let mut lazy_value = Lazy::new_reusable(|| String::from("moo"));
+
+    assert_eq!(lazy_value.take_if_evaluated(), None);
+    assert_eq!(lazy_value.len(), 3);
+    assert_eq!(lazy_value.take_if_evaluated(), Some(String::from("moo")));
+    assert_eq!(lazy_value.take_if_evaluated(), None);
+
+    lazy_value.reset_reusing();
+
+    assert_eq!(*lazy_value, "moo");
+}
+
+#[test]
+fn lazy_map() {
+    let evaluator_called_times = Cell::new(0);
+    let mapping_called_times = Cell::new(0);
+
+    let lazy_len = Lazy::new(|| {
+            evaluator_called_times.set(evaluator_called_times.get() + 1);
+            String::from("lorem ipsum")
+        })
+        .map(|string| {
+            mapping_called_times.set(mapping_called_times.get() + 1);
+            string.len()
+        });
+
+    assert_eq!(evaluator_called_times.get(), 0);
+    assert_eq!(mapping_called_times.get(), 0);
+
+    assert_eq!(*lazy_len, 11);
+    assert_eq!(*lazy_len, 11);
+
+    assert_eq!(evaluator_called_times.get(), 1);
+    assert_eq!(mapping_called_times.get(), 1);
+}
+
+#[test]
+fn lazy_map_chained() {
+    let lazy_value = Lazy::new(|| 2)
+        .map(|value| value + 1)
+        .map(|value| value * 10)
+        .map(|value| value.to_string());
+
+    assert_eq!(lazy_value.as_str(), "30");
+}
+
+#[test]
+fn lazy_vec_count() {
+    let mut evaluator_called_times = 0;
+    let lazy_vec = Lazy::new(|| {
+        evaluator_called_times += 1;
+        vec![1, 2, 3]
+    });
+
+    assert_eq!(lazy_vec.count(), 3);
+    assert_eq!(lazy_vec.count(), 3);
+    assert_eq!(evaluator_called_times, 1);
+}
+
+#[test]
+fn lazy_vec_count_if_evaluated() {
+    let evaluator_called_times = Cell::new(0);
+    let mut lazy_vec = Lazy::new(|| {
+        evaluator_called_times.set(evaluator_called_times.get() + 1);
+        vec![1, 2, 3]
+    });
+
+    assert_eq!(lazy_vec.count_if_evaluated(), None);
+    assert_eq!(evaluator_called_times.get(), 0);
+
+    lazy_vec.push(4);
+
+    assert_eq!(lazy_vec.count_if_evaluated(), Some(4));
+    assert_eq!(evaluator_called_times.get(), 1);
+}
+
+#[test]
+fn lazy_result_try_unwrap_ok() {
+    fn parse_sum(lazy_numbers: Lazy<Result<Vec<i32>, String>, impl FnOnce() -> Result<Vec<i32>, String>>) -> Result<i32, String> {
+        Ok(lazy_numbers.try_unwrap()?.into_iter().sum())
+    }
+
+    let mut evaluator_called_times = 0;
+    let lazy_numbers = Lazy::new(|| {
+        evaluator_called_times += 1;
+        Ok(vec![1, 2, 3])
+    });
+
+    assert_eq!(lazy_numbers.try_as_ref(), Ok(&vec![1, 2, 3]));
+    assert_eq!(parse_sum(lazy_numbers), Ok(6));
+    assert_eq!(evaluator_called_times, 1);
+}
+
+#[test]
+fn lazy_result_try_unwrap_err() {
+    let mut evaluator_called_times = 0;
+    let lazy_result: Lazy<Result<i32, String>, _> = Lazy::new(|| {
+        evaluator_called_times += 1;
+        Err(String::from("failure"))
+    });
+
+    assert_eq!(lazy_result.try_as_ref(), Err(&String::from("failure")));
+    assert_eq!(lazy_result.try_unwrap(), Err(String::from("failure")));
+    assert_eq!(evaluator_called_times, 1);
+}
+
+#[test]
+fn lru_lazy_pool_evicts_least_recently_used() {
+    let drop_count = Cell::new(0);
+    let evaluator_called_times = Cell::new(0);
+
+    let mut pool = LruLazyPool::new(2);
+
+    for key in 0..3 {
+        let drop_count = &drop_count;
+        let evaluator_called_times = &evaluator_called_times;
+
+        pool.insert(key, move || {
+            evaluator_called_times.set(evaluator_called_times.get() + 1);
+            (key * 10, SomethingDropCounting{drop_count})
+        });
+    }
+
+    assert_eq!(pool.get(&0).map(|value| value.0), Some(0));
+    assert_eq!(pool.get(&1).map(|value| value.0), Some(10));
+    assert_eq!(pool.get(&0).map(|value| value.0), Some(0));
+    assert_eq!(evaluator_called_times.get(), 2);
+    assert_eq!(drop_count.get(), 0);
+
+    assert_eq!(pool.get(&2).map(|value| value.0), Some(20));
+
+    assert_eq!(evaluator_called_times.get(), 3);
+    assert_eq!(drop_count.get(), 1);
+    assert!(pool.is_evaluated(&0));
+    assert!(!pool.is_evaluated(&1));
+    assert!(pool.is_evaluated(&2));
+
+    assert_eq!(pool.get(&1).map(|value| value.0), Some(10));
+
+    assert_eq!(evaluator_called_times.get(), 4);
+    assert_eq!(drop_count.get(), 2);
+    assert!(!pool.is_evaluated(&0));
+}
+
+#[test]
+fn lru_lazy_pool_missing_key() {
+    let mut pool = LruLazyPool::new(1);
+
+    pool.insert("answer", || 42);
+
+    assert_eq!(pool.get(&"question"), None);
+    assert!(!pool.is_evaluated(&"answer"));
+    assert_eq!(pool.capacity(), 1);
+}
+
+#[test]
+#[should_panic]
+fn lru_lazy_pool_zero_capacity() {
+    let _pool: LruLazyPool<i32, i32> = LruLazyPool::new(0);
+}
+
+#[test]
+fn lru_lazy_pool_panicking_evaluator() {
+    let should_panic = Cell::new(true);
+    let evaluator_called_times = Cell::new(0);
+
+    let mut pool = LruLazyPool::new(1);
+
+    pool.insert("flaky", || {
+        evaluator_called_times.set(evaluator_called_times.get() + 1);
+
+        if should_panic.replace(false) {
+            panic!("flaky evaluator");
+        }
+
+        1
+    });
+    pool.insert("stable", || 2);
+
+    let access_result = panic::catch_unwind(AssertUnwindSafe(|| pool.get(&"flaky").copied()));
+
+    assert!(access_result.is_err());
+    assert!(!pool.is_evaluated(&"flaky"));
+
+    assert_eq!(pool.get(&"stable"), Some(&2));
+    assert!(pool.is_evaluated(&"stable"));
+
+    assert_eq!(pool.get(&"flaky"), Some(&1));
+    assert!(pool.is_evaluated(&"flaky"));
+    assert!(!pool.is_evaluated(&"stable"));
+    assert_eq!(evaluator_called_times.get(), 2);
+
+    assert_eq!(pool.get(&"stable"), Some(&2));
+    assert!(!pool.is_evaluated(&"flaky"));
+}
+
+#[test]
+fn lru_lazy_pool_insert_replaces_evaluated() {
+    let mut pool = LruLazyPool::new(2);
+
+    pool.insert(0, || 0);
+    pool.insert(1, || 10);
+
+    pool.get(&0);
+    pool.get(&1);
+
+    pool.insert(0, || 100);
+
+    assert!(!pool.is_evaluated(&0));
+    assert_eq!(pool.get(&0), Some(&100));
+    assert!(pool.is_evaluated(&1));
+}
+
+#[test]
+fn sync_lazy_static() {
+    static EVALUATOR_CALL_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+    fn evaluate() -> Vec<i32> {
+        EVALUATOR_CALL_COUNT.fetch_add(1, Ordering::SeqCst);
+        vec![1, 2, 3]
+    }
+
+    static LAZY_VALUE: SyncLazy<Vec<i32>, fn() -> Vec<i32>> = SyncLazy::new(evaluate);
+
+    let handles: Vec<_> = (0..8)
+        .map(|_| thread::spawn(|| LAZY_VALUE.iter().sum::<i32>()))
+        .collect();
+
+    for handle in handles {
+        assert_eq!(handle.join().expect("thread must not panic"), 6);
+    }
+
+    assert_eq!(LAZY_VALUE.as_ref(), &vec![1, 2, 3]);
+    assert_eq!(EVALUATOR_CALL_COUNT.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn sync_lazy_scoped_threads() {
+    let evaluator_call_count = AtomicUsize::new(0);
+
+    let lazy_value = SyncLazy::new(|| {
+        evaluator_call_count.fetch_add(1, Ordering::SeqCst);
+        thread::sleep(Duration::from_millis(10));
+        42
+    });
+
+    thread::scope(|scope| {
+        for _ in 0..8 {
+            scope.spawn(|| assert_eq!(*lazy_value.as_ref(), 42));
+        }
+    });
+
+    assert_eq!(evaluator_call_count.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn cloning_lazy_get() {
+    let evaluator_called_times = Cell::new(0);
+    let lazy_template = CloningLazy::new(|| {
+        evaluator_called_times.set(evaluator_called_times.get() + 1);
+        vec![String::from("lorem")]
+    });
+
+    assert_eq!(evaluator_called_times.get(), 0);
+
+    let mut first = lazy_template.get();
+    let mut second = lazy_template.get();
+
+    first.push(String::from("ipsum"));
+    second[0].push_str(" dolor");
+
+    assert_eq!(first, vec!["lorem", "ipsum"]);
+    assert_eq!(second, vec!["lorem dolor"]);
+    assert_eq!(lazy_template.get(), vec!["lorem"]);
+    assert_eq!(evaluator_called_times.get(), 1);
+}
+
+#[test]
+fn lazy_clone_evaluated() {
+    let evaluator_called_times = Cell::new(0);
+    let lazy_value = Lazy::new(|| {
+        evaluator_called_times.set(evaluator_called_times.get() + 1);
+        String::from("moo")
+    });
+
+    lazy_value.force();
+
+    let mut lazy_clone = lazy_value.clone();
+
+    assert!(lazy_clone.is_evaluated());
+
+    lazy_clone.push_str("se");
+
+    assert_eq!(*lazy_clone, "moose");
+    assert_eq!(*lazy_value, "moo");
+    assert_eq!(evaluator_called_times.get(), 1);
+}
+
+#[test]
+fn lazy_clone_unevaluated() {
+    let evaluator_called_times = Cell::new(0);
+    let lazy_value = Lazy::new(|| {
+        evaluator_called_times.set(evaluator_called_times.get() + 1);
+        String::from("moo")
+    });
+
+    let lazy_clone = lazy_value.clone();
+
+    assert!(!lazy_clone.is_evaluated());
+    assert_eq!(evaluator_called_times.get(), 0);
+
+    assert_eq!(*lazy_clone, "moo");
+    assert_eq!(evaluator_called_times.get(), 1);
+    assert!(!lazy_value.is_evaluated());
+
+    assert_eq!(*lazy_value, "moo");
+    assert_eq!(evaluator_called_times.get(), 2);
+}
+
+#[test]
+fn lazy_clone_derived_for_containing_struct() {
+    #[derive(Clone)]
+    struct Config {
+        name: Lazy<String, fn() -> String>
+    }
+
+    let config = Config{name: Lazy::new(|| String::from("moo"))};
+    let config_clone = config.clone();
+
+    assert_eq!(*config.name, *config_clone.name);
+}
+
+#[test]
+fn keyed_lazy_evaluates_each_key_once() {
+    let first_called_times = Cell::new(0);
+    let second_called_times = Cell::new(0);
+    let untouched_called_times = Cell::new(0);
+
+    let mut lazy_values = KeyedLazy::new();
+
+    lazy_values.insert("first", || {
+        first_called_times.set(first_called_times.get() + 1);
+        String::from("lorem")
+    });
+    lazy_values.insert("second", || {
+        second_called_times.set(second_called_times.get() + 1);
+        String::from("ipsum")
+    });
+    lazy_values.insert("untouched", || {
+        untouched_called_times.set(untouched_called_times.get() + 1);
+        String::from("dolor")
+    });
+
+    assert_eq!(lazy_values.get(&"first").map(String::as_str), Some("lorem"));
+    assert_eq!(lazy_values.get(&"first").map(String::as_str), Some("lorem"));
+    assert_eq!(lazy_values.get(&"second").map(String::as_str), Some("ipsum"));
+    assert_eq!(lazy_values.get(&"missing"), None);
+
+    assert!(lazy_values.is_evaluated(&"first"));
+    assert!(!lazy_values.is_evaluated(&"untouched"));
+
+    assert_eq!(first_called_times.get(), 1);
+    assert_eq!(second_called_times.get(), 1);
+    assert_eq!(untouched_called_times.get(), 0);
+}
+
+#[test]
+fn lazy_reset_evaluated() {
+    let drop_count = Cell::new(0);
+    let evaluator_called_times = Cell::new(0);
+
+    let evaluator = |value| {
+        let drop_count = &drop_count;
+        let evaluator_called_times = &evaluator_called_times;
+        move || {
+            evaluator_called_times.set(evaluator_called_times.get() + 1);
+            (value, SomethingDropCounting{drop_count})
+        }
+    };
+
+    let mut lazy_value: Lazy<_, Box<dyn FnOnce() -> _>> = Lazy::new(Box::new(evaluator(1)));
+
+    assert_eq!(lazy_value.0, 1);
+
+    lazy_value.reset(Box::new(evaluator(2)));
+
+    assert_eq!(drop_count.get(), 1);
+    assert!(!lazy_value.is_evaluated());
+
+    assert_eq!(lazy_value.0, 2);
+    assert_eq!(evaluator_called_times.get(), 2);
+    assert_eq!(drop_count.get(), 1);
+}
+
+#[test]
+fn lazy_reset_unevaluated() {
+    let mut lazy_value: Lazy<i32, fn() -> i32> = Lazy::new(|| unreachable!());
+
+    lazy_value.reset(|| 42);
+
+    assert_eq!(*lazy_value, 42);
+}
+
+#[test]
+fn lazy_new_cancellable_cancelled() {
+    let evaluator_called_times = Cell::new(0);
+    let token = CancelToken::new();
+
+    let lazy_value = Lazy::new_cancellable(token.clone(), || {
+        evaluator_called_times.set(evaluator_called_times.get() + 1);
+        42
+    });
+
+    token.cancel();
+
+    assert!(token.is_cancelled());
+    assert_eq!(*lazy_value, None);
+    assert_eq!(*lazy_value, None);
+    assert_eq!(evaluator_called_times.get(), 0);
+}
+
+#[test]
+fn lazy_new_cancellable_not_cancelled() {
+    let evaluator_called_times = Cell::new(0);
+    let token = CancelToken::new();
+
+    let lazy_value = Lazy::new_cancellable(token.clone(), || {
+        evaluator_called_times.set(evaluator_called_times.get() + 1);
+        42
+    });
+
+    assert_eq!(*lazy_value, Some(42));
+
+    token.cancel();
+
+    assert_eq!(*lazy_value, Some(42));
+    assert_eq!(evaluator_called_times.get(), 1);
+}
+
+#[test]
+fn lazy_new_cancellable_cancelled_from_other_thread() {
+    let token = CancelToken::new();
+
+    let lazy_value = Lazy::new_cancellable(token.clone(), || 42);
+
+    thread::spawn(move || token.cancel())
+        .join()
+        .expect("thread must not panic");
+
+    assert_eq!(*lazy_value, None);
+}
+
+#[test]
+fn lazy_into_inner_evaluated() {
+    let mut lazy_value = Lazy::new(|| vec![1, 2]);
+
+    lazy_value.push(3);
+
+    assert_eq!(lazy_value.peek(), Some(&vec![1, 2, 3]));
+    assert_eq!(lazy_value.into_inner(), Some(vec![1, 2, 3]));
+}
+
+#[test]
+fn lazy_into_inner_unevaluated() {
+    let evaluator_called_times = Cell::new(0);
+    let lazy_value = Lazy::new(|| {
+        evaluator_called_times.set(evaluator_called_times.get() + 1);
+        vec![1, 2]
+    });
+
+    assert_eq!(lazy_value.peek(), None);
+    assert_eq!(lazy_value.into_inner(), None);
+    assert_eq!(evaluator_called_times.get(), 0);
+}
+
+#[test]
+fn lazy_vec_string_as_str_slices() {
+    fn join_words(words: &[&str]) -> String {
+        words.join(" ")
+    }
+
+    let mut evaluator_called_times = 0;
+    let lazy_words = Lazy::new(|| {
+        evaluator_called_times += 1;
+        vec![String::from("lorem"), String::from("ipsum")]
+    });
+
+    let slices = lazy_words.as_str_slices();
+
+    assert_eq!(slices, vec!["lorem", "ipsum"]);
+    assert_eq!(join_words(&slices), "lorem ipsum");
+    assert_eq!(join_words(&lazy_words.as_str_slices()), "lorem ipsum");
+
+    drop(lazy_words);
+
+    assert_eq!(evaluator_called_times, 1);
+}
+
+#[test]
+fn lazy_from_value() {
+    let lazy_value = Lazy::from_value(String::from("moo"));
+
+    assert!(lazy_value.is_evaluated());
+    assert_eq!(lazy_value.state(), LazyState::Evaluated);
+    assert_eq!(*lazy_value, "moo");
+    assert_eq!(lazy_value.len(), 3);
+    assert_eq!(lazy_value.unwrap(), "moo");
+}
+
+#[test]
+fn lazy_from_value_same_type_as_new() {
+    fn lazy_number(precomputed: Option<i32>) -> Lazy<i32, fn() -> i32> {
+        match precomputed {
+            Some(value) => Lazy::from_value(value),
+            None        => Lazy::new(|| 42)
+        }
+    }
+
+    assert_eq!(*lazy_number(Some(7)), 7);
+    assert_eq!(*lazy_number(None), 42);
+}
+
+#[test]
+fn lazy_new_boxed_in_vec() {
+    let evaluator_call_count = Rc::new(Cell::new(0));
+
+    let lazies: Vec<BoxedLazy<i32>> = (1..=3)
+        .map(|value| {
+            let evaluator_call_count = Rc::clone(&evaluator_call_count);
+            Lazy::new_boxed(move || {
+                evaluator_call_count.set(evaluator_call_count.get() + 1);
+                value * 10
+            })
+        })
+        .chain(iter::once(Lazy::new_boxed(|| 40)))
+        .collect();
+
+    assert_eq!(evaluator_call_count.get(), 0);
+
+    assert_eq!(*lazies[1], 20);
+    assert_eq!(lazies.iter().map(|lazy| **lazy).sum::<i32>(), 100);
+    assert_eq!(evaluator_call_count.get(), 3);
+}
+
+#[test]
+fn lazy_new_boxed_struct_field() {
+    struct Config {
+        port: BoxedLazy<u16>
+    }
+
+    let config = Config{port: Lazy::new_boxed(|| 8080)};
+
+    assert_eq!(*config.port, 8080);
+}
+
+#[test]
+#[cfg(feature = "arc-swap")]
+fn swappable_lazy_load_and_store() {
+    let evaluator_call_count = AtomicUsize::new(0);
+    let lazy_value = SwappableLazy::new(|| {
+        evaluator_call_count.fetch_add(1, Ordering::SeqCst);
+        vec![1, 2, 3]
+    });
+
+    assert!(!lazy_value.is_evaluated());
+    assert_eq!(evaluator_call_count.load(Ordering::SeqCst), 0);
+
+    let old_value = lazy_value.load();
+
+    lazy_value.store(vec![4, 5]);
+
+    assert_eq!(*old_value, vec![1, 2, 3]);
+    assert_eq!(*lazy_value.load(), vec![4, 5]);
+    assert_eq!(evaluator_call_count.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+#[cfg(feature = "arc-swap")]
+fn swappable_lazy_store_before_load() {
+    let lazy_value = SwappableLazy::new(|| -> i32 { unreachable!() });
+
+    lazy_value.store(42);
+
+    assert!(lazy_value.is_evaluated());
+    assert_eq!(*lazy_value.load(), 42);
+}
+
+#[test]
+#[cfg(feature = "arc-swap")]
+fn swappable_lazy_concurrent_readers() {
+    let evaluator_call_count = AtomicUsize::new(0);
+    let lazy_value = SwappableLazy::new(|| {
+        evaluator_call_count.fetch_add(1, Ordering::SeqCst);
+        thread::sleep(Duration::from_millis(10));
+        (0, 0)
+    });
+
+    thread::scope(|scope| {
+        for _ in 0..4 {
+            scope.spawn(|| {
+                for _ in 0..100 {
+                    let value = lazy_value.load();
+
+                    assert_eq!(value.0, value.1);
+                    assert!(value.0 <= 10);
+                }
+            });
+        }
+
+        scope.spawn(|| {
+            for generation in 1..=10 {
+                lazy_value.store((generation, generation));
+            }
+        });
+    });
+
+    assert_eq!(*lazy_value.load(), (10, 10));
+    assert!(evaluator_call_count.load(Ordering::SeqCst) <= 1);
+}
+
+#[test]
+fn lazy_eq_equal_values() {
+    let first_called_times = Cell::new(0);
+    let second_called_times = Cell::new(0);
+
+    let first = Lazy::new(|| {
+        first_called_times.set(first_called_times.get() + 1);
+        String::from("moo")
+    });
+    let second = Lazy::new(|| {
+        second_called_times.set(second_called_times.get() + 1);
+        "moo".to_string()
+    });
+
+    assert!(first == second);
+    assert_eq!(second, first);
+    assert_eq!(first_called_times.get(), 1);
+    assert_eq!(second_called_times.get(), 1);
+}
+
+#[test]
+fn lazy_eq_unequal_values() {
+    let first_called_times = Cell::new(0);
+    let second_called_times = Cell::new(0);
+
+    let first = Lazy::new(|| {
+        first_called_times.set(first_called_times.get() + 1);
+        vec![1, 2]
+    });
+    let second = Lazy::new(|| {
+        second_called_times.set(second_called_times.get() + 1);
+        vec![1, 2, 3]
+    });
+
+    assert!(first != second);
+    assert_ne!(second, first);
+    assert_eq!(first_called_times.get(), 1);
+    assert_eq!(second_called_times.get(), 1);
+}
+
+#[test]
+fn lazy_vec_try_map_each_ok() {
+    let evaluator_called_times = Cell::new(0);
+    let mapping_called_times = Cell::new(0);
+
+    let lazy_numbers = Lazy::new(|| {
+            evaluator_called_times.set(evaluator_called_times.get() + 1);
+            vec!["1", "2", "3"]
+        })
+        .try_map_each(|string| {
+            mapping_called_times.set(mapping_called_times.get() + 1);
+            string.parse::<i32>()
+        });
+
+    assert_eq!(evaluator_called_times.get(), 0);
+    assert_eq!(mapping_called_times.get(), 0);
+
+    assert_eq!(*lazy_numbers, Ok(vec![1, 2, 3]));
+    assert_eq!(evaluator_called_times.get(), 1);
+    assert_eq!(mapping_called_times.get(), 3);
+}
+
+#[test]
+fn lazy_vec_try_map_each_err() {
+    let mapping_called_times = Cell::new(0);
+
+    let lazy_numbers = Lazy::new(|| vec!["1", "two", "3"])
+        .try_map_each(|string| {
+            mapping_called_times.set(mapping_called_times.get() + 1);
+            string.parse::<i32>().map_err(|_| string)
+        });
+
+    assert_eq!(mapping_called_times.get(), 0);
+
+    assert_eq!(*lazy_numbers, Err("two"));
+    assert_eq!(mapping_called_times.get(), 2);
+}
+
+#[test]
+#[allow(clippy::mutable_key_type)] // the hashed value no longer changes once evaluated by hashing
+fn lazy_hash_set_lookup() {
+    let evaluator_call_count = Rc::new(Cell::new(0));
+
+    let lazy_strings: HashSet<BoxedLazy<String>> = ["lorem", "ipsum"].iter()
+        .map(|string| {
+            let evaluator_call_count = Rc::clone(&evaluator_call_count);
+            Lazy::new_boxed(move || {
+                evaluator_call_count.set(evaluator_call_count.get() + 1);
+                string.to_string()
+            })
+        })
+        .collect();
+
+    assert!(lazy_strings.contains(&String::from("lorem")));
+    assert!(lazy_strings.contains(&String::from("ipsum")));
+    assert!(!lazy_strings.contains(&String::from("dolor")));
+    assert!(lazy_strings.contains(&Lazy::new_boxed(|| String::from("ipsum"))));
+    assert_eq!(evaluator_call_count.get(), 2);
+}
+
+#[test]
+fn lazy_hash_consistent_with_value() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    fn hash_of<H: Hash>(value: &H) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    assert_eq!(hash_of(&Lazy::new(|| 42)), hash_of(&42));
+}
+
+#[test]
+fn timed_lazy_eval_duration() {
+    let mut evaluator_called_times = 0;
+    let mut lazy_value = Lazy::timed(|| {
+        evaluator_called_times += 1;
+        thread::sleep(Duration::from_millis(5));
+        vec![1, 2, 3]
+    });
+
+    assert_eq!(lazy_value.eval_duration(), None);
+
+    lazy_value.push(4);
+
+    let eval_duration = lazy_value.eval_duration().expect("duration must be recorded after evaluation");
+
+    assert!(eval_duration >= Duration::from_millis(5));
+    assert_eq!(*lazy_value, vec![1, 2, 3, 4]);
+    assert_eq!(lazy_value.as_ref().len(), 4);
+    assert_eq!(lazy_value.eval_duration(), Some(eval_duration));
+
+    drop(lazy_value);
+
+    assert_eq!(evaluator_called_times, 1);
+}
+
+#[test]
+fn timed_lazy_unused() {
+    let lazy_value = Lazy::timed(|| -> i32 { unreachable!() });
+
+    assert_eq!(lazy_value.eval_duration(), None);
+    assert!(!lazy_value.into_lazy().is_evaluated());
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct SomethingWithLazyField {
+    name:  String,
+    value: BoxedLazy<i32>
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn lazy_serde_round_trip() {
+    let evaluator_called_times = Rc::new(Cell::new(0));
+
+    let something = SomethingWithLazyField{
+        name:  "answer".to_string(),
+        value: Lazy::new_boxed({
+            let evaluator_called_times = Rc::clone(&evaluator_called_times);
+            move || {
+                evaluator_called_times.set(evaluator_called_times.get() + 1);
+                42
+            }
+        })
+    };
+
+    let json = serde_json::to_string(&something).unwrap();
+
+    assert_eq!(json, r#"{"name":"answer","value":42}"#);
+    assert_eq!(evaluator_called_times.get(), 1);
+    assert_eq!(serde_json::to_string(&something).unwrap(), json);
+    assert_eq!(evaluator_called_times.get(), 1);
+
+    let deserialized: SomethingWithLazyField = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(deserialized.name, "answer");
+    assert!(deserialized.value.is_evaluated());
+    assert_eq!(*deserialized.value, 42);
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn lazy_serde_deserialize_fn_evaluator() {
+    let lazy_value: Lazy<Vec<u8>, fn() -> Vec<u8>> = serde_json::from_str("[1,2,3]").unwrap();
+
+    assert!(lazy_value.is_evaluated());
+    assert_eq!(*lazy_value, vec![1, 2, 3]);
+    assert!(serde_json::from_str::<Lazy<i32>>("\"not a number\"").is_err());
+}
+
+#[test]
+fn lazy_with_evaluator_const() {
+    fn make_vec() -> Vec<i32> {
+        vec![1, 2, 3]
+    }
+
+    const fn make_lazy_vec() -> Lazy<Vec<i32>, fn() -> Vec<i32>> {
+        Lazy::with_evaluator(make_vec)
+    }
+
+    let mut lazy_value = make_lazy_vec();
+    let other_lazy_value = make_lazy_vec();
+
+    assert!(!lazy_value.is_evaluated());
+
+    lazy_value.push(4);
+
+    assert_eq!(*lazy_value, vec![1, 2, 3, 4]);
+    assert!(!other_lazy_value.is_evaluated());
+    assert_eq!(*other_lazy_value, vec![1, 2, 3]);
+}
+
+#[test]
+fn lazy_with_evaluator_thread_local() {
+    thread_local! {
+        static EVALUATOR_CALLED_TIMES: Cell<usize> = const { Cell::new(0) };
+        static LAZY_VALUE: Lazy<String, fn() -> String> = const {
+            Lazy::with_evaluator(|| {
+                EVALUATOR_CALLED_TIMES.with(|called_times| called_times.set(called_times.get() + 1));
+                "hello".to_string()
+            })
+        };
+    }
+
+    LAZY_VALUE.with(|lazy_value| assert_eq!(lazy_value.as_str(), "hello"));
+    LAZY_VALUE.with(|lazy_value| assert_eq!(lazy_value.len(), 5));
+
+    assert_eq!(EVALUATOR_CALLED_TIMES.with(Cell::get), 1);
+}
+
+#[test]
+fn lazy_flatten_result() {
+    let evaluator_called_times = Cell::new(0);
+    let make_lazy_result = |nested_result: Result<Result<i32, String>, String>| {
+        let evaluator_called_times = &evaluator_called_times;
+
+        Lazy::new(move || {
+            evaluator_called_times.set(evaluator_called_times.get() + 1);
+            nested_result
+        }).flatten_result()
+    };
+
+    let lazy_inner_ok  = make_lazy_result(Ok(Ok(42)));
+    let lazy_inner_err = make_lazy_result(Ok(Err("inner".to_string())));
+    let lazy_outer_err = make_lazy_result(Err("outer".to_string()));
+
+    assert_eq!(evaluator_called_times.get(), 0);
+
+    assert_eq!(*lazy_inner_ok, Ok(42));
+    assert_eq!(*lazy_inner_err, Err("inner".to_string()));
+    assert_eq!(*lazy_outer_err, Err("outer".to_string()));
+    assert_eq!(*lazy_inner_ok, Ok(42));
+
+    assert_eq!(evaluator_called_times.get(), 3);
+}
+
+#[test]
+fn lazy_tap_if_evaluated() {
+    let mut evaluator_called_times = 0;
+    let tapped_values = RefCell::new(Vec::new());
+
+    let lazy_value = Lazy::new(|| {
+        evaluator_called_times += 1;
+        42
+    });
+
+    let lazy_value = lazy_value.tap_if_evaluated(|value| tapped_values.borrow_mut().push(*value));
+
+    assert!(tapped_values.borrow().is_empty());
+    assert!(!lazy_value.is_evaluated());
+    assert_eq!(*lazy_value, 42);
+
+    let lazy_value = lazy_value
+        .tap_if_evaluated(|value| tapped_values.borrow_mut().push(*value))
+        .tap_if_evaluated(|value| tapped_values.borrow_mut().push(*value + 1));
+
+    assert_eq!(*tapped_values.borrow(), [42, 43]);
+    assert_eq!(lazy_value.into_inner(), Some(42));
+    assert_eq!(evaluator_called_times, 1);
+}
+
+#[test]
+fn memoized_invokes_function_once_per_key() {
+    let function_calls = RefCell::new(Vec::new());
+    let lengths = Memoized::new(|word: &&str| {
+        function_calls.borrow_mut().push(*word);
+        word.len()
+    });
+
+    let first_length = lengths.get("sloth");
+
+    assert_eq!(*lengths.get("lazy"), 4);
+    assert_eq!(*lengths.get("sloth"), 5);
+    assert_eq!(*lengths.get("lazy"), 4);
+    assert_eq!(*first_length, 5);
+    assert!(Rc::ptr_eq(&first_length, &lengths.get("sloth")));
+
+    assert!(lengths.is_memoized(&"sloth"));
+    assert!(!lengths.is_memoized(&"never"));
+
+    assert_eq!(*function_calls.borrow(), ["sloth", "lazy"]);
+}
+
+#[test]
+fn memoized_recursive() {
+    type DynMemoized = Memoized<u64, u64, Box<dyn Fn(&u64) -> u64>>;
+
+    let function_calls = Rc::new(RefCell::new(Vec::new()));
+    let fibonacci_cell: Rc<OnceCell<Weak<DynMemoized>>> = Rc::new(OnceCell::new());
+
+    let fibonacci: Rc<DynMemoized> = Rc::new(Memoized::new(Box::new({
+        let function_calls = Rc::clone(&function_calls);
+        let fibonacci_cell = Rc::clone(&fibonacci_cell);
+
+        move |n: &u64| {
+            RefCell::borrow_mut(&function_calls).push(*n);
+
+            match *n {
+                0 | 1 => *n,
+                _     => {
+                    let fibonacci = fibonacci_cell.get().and_then(Weak::upgrade).unwrap();
+
+                    *fibonacci.get(n - 1) + *fibonacci.get(n - 2)
+                }
+            }
+        }
+    })));
+
+    fibonacci_cell.set(Rc::downgrade(&fibonacci)).ok();
+
+    assert_eq!(*fibonacci.get(10), 55);
+    assert_eq!(*fibonacci.get(9), 34);
+
+    assert_eq!(*RefCell::borrow(&function_calls), [10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0]);
+}
+
+#[test]
+fn lazy_f64_approx_eq() {
+    let evaluator_called_times = Cell::new(0);
+    let lazy_value = Lazy::new(|| {
+        evaluator_called_times.set(evaluator_called_times.get() + 1);
+        0.1_f64 + 0.2
+    });
+
+    assert!(lazy_value.approx_eq(0.3, 1e-9));
+    assert!(lazy_value.approx_eq(0.3 + 0.5e-9, 1e-9));
+    assert!(!lazy_value.approx_eq(0.3 + 2e-9, 1e-9));
+    assert!(!lazy_value.approx_eq(0.3, 0.0));
+    assert!(!lazy_value.approx_eq(f64::NAN, 1.0));
+    assert!(!lazy_value.approx_eq(f64::INFINITY, 1.0));
+
+    assert_eq!(evaluator_called_times.get(), 1);
+
+    let lazy_infinity = Lazy::new(|| f64::INFINITY);
+
+    assert!(lazy_infinity.approx_eq(f64::INFINITY, 1e-9));
+    assert!(!lazy_infinity.approx_eq(f64::NEG_INFINITY, 1e-9));
+    assert!(!lazy_infinity.approx_eq(f64::MAX, 1e-9));
+}
+
+#[test]
+fn lazy_f32_approx_eq() {
+    let evaluator_called_times = Cell::new(0);
+    let lazy_value = Lazy::new(|| {
+        evaluator_called_times.set(evaluator_called_times.get() + 1);
+        1.0_f32 / 3.0
+    });
+
+    assert!(lazy_value.approx_eq(0.333_333, 1e-5));
+    assert!(!lazy_value.approx_eq(0.333, 1e-5));
+    assert!(lazy_value.approx_eq(0.333, 1e-3));
+
+    assert_eq!(evaluator_called_times.get(), 1);
+}
+
+#[test]
+#[cfg(feature = "async")]
+fn async_lazy_get_awaits_once() {
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+
+    let future_started_times   = Arc::new(AtomicUsize::new(0));
+    let future_completed_times = Arc::new(AtomicUsize::new(0));
+
+    let lazy_value = Arc::new(AsyncLazy::new({
+        let future_started_times   = Arc::clone(&future_started_times);
+        let future_completed_times = Arc::clone(&future_completed_times);
+
+        async move {
+            future_started_times.fetch_add(1, Ordering::SeqCst);
+
+            for _ in 0..10 {
+                tokio::time::sleep(Duration::from_millis(1)).await;
+            }
+
+            future_completed_times.fetch_add(1, Ordering::SeqCst);
+
+            vec![1, 2, 3]
+        }
+    }));
+
+    assert_eq!(lazy_value.peek(), None);
+
+    let values = runtime.block_on(async {
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let lazy_value = Arc::clone(&lazy_value);
+
+                tokio::spawn(async move { lazy_value.get().await.clone() })
+            })
+            .collect();
+
+        let mut values = Vec::new();
+        for handle in handles {
+            values.push(handle.await.unwrap());
+        }
+
+        values.push(lazy_value.get().await.clone());
+
+        values
+    });
+
+    assert!(values.iter().all(|value| *value == [1, 2, 3]));
+    assert_eq!(lazy_value.peek(), Some(&vec![1, 2, 3]));
+
+    assert_eq!(future_started_times.load(Ordering::SeqCst), 1);
+    assert_eq!(future_completed_times.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+#[cfg(feature = "async")]
+fn async_lazy_never_polled_unless_requested() {
+    let future_started_times = Arc::new(AtomicUsize::new(0));
+
+    let lazy_value = AsyncLazy::new({
+        let future_started_times = Arc::clone(&future_started_times);
+
+        async move {
+            future_started_times.fetch_add(1, Ordering::SeqCst);
+        }
+    });
+
+    assert_eq!(lazy_value.peek(), None);
+
+    drop(lazy_value);
+
+    assert_eq!(future_started_times.load(Ordering::SeqCst), 0);
+}
+
+#[test]
+fn recomputing_lazy_invalidate() {
+    let evaluator_called_times = Cell::new(0);
+    let mut fibonacci_state = (0_u64, 1_u64);
+
+    let mut lazy_fibonacci = RecomputingLazy::new(|| {
+        evaluator_called_times.set(evaluator_called_times.get() + 1);
+        fibonacci_state = (fibonacci_state.1, fibonacci_state.0 + fibonacci_state.1);
+        fibonacci_state.0
+    });
+
+    assert!(!lazy_fibonacci.is_evaluated());
+    assert_eq!(evaluator_called_times.get(), 0);
+
+    let mut values = Vec::new();
+    for _ in 0..6 {
+        values.push(*lazy_fibonacci);
+        values.push(*lazy_fibonacci.as_ref());
+
+        lazy_fibonacci.invalidate();
+
+        assert!(!lazy_fibonacci.is_evaluated());
+    }
+
+    assert_eq!(values, [1, 1, 1, 1, 2, 2, 3, 3, 5, 5, 8, 8]);
+    assert_eq!(evaluator_called_times.get(), 6);
+}
+
+#[test]
+fn recomputing_lazy_mutate_then_invalidate() {
+    let mut lazy_value = RecomputingLazy::new(|| vec![1, 2, 3]);
+
+    lazy_value.push(4);
+
+    assert_eq!(*lazy_value, [1, 2, 3, 4]);
+
+    lazy_value.invalidate();
+
+    assert_eq!(*lazy_value, [1, 2, 3]);
+
+    lazy_value.invalidate();
+    lazy_value.as_mut().clear();
+
+    assert!(lazy_value.is_empty());
+}
+
+#[test]
+fn recomputing_lazy_debounced_invalidate() {
+    let clock = MockClock::new();
+    let evaluator_called_times = Cell::new(0);
+    let evicted_values = RefCell::new(Vec::new());
+
+    let mut lazy_value = RecomputingLazy::new_debounced(|| {
+        evaluator_called_times.set(evaluator_called_times.get() + 1);
+        evaluator_called_times.get()
+    }, Duration::from_secs(1), &clock);
+    lazy_value.on_evict(|value| evicted_values.borrow_mut().push(*value));
+
+    assert_eq!(*lazy_value, 1);
+
+    lazy_value.invalidate();
+
+    assert!(!lazy_value.is_evaluated());
+    assert_eq!(*lazy_value, 2);
+
+    for _ in 0..5 {
+        clock.advance(Duration::from_millis(100));
+        lazy_value.invalidate();
+        lazy_value.invalidate();
+
+        assert!(lazy_value.is_evaluated());
+        assert_eq!(*lazy_value, 2);
+    }
+
+    clock.advance(Duration::from_millis(500));
+    lazy_value.invalidate();
+    lazy_value.invalidate();
+
+    assert!(!lazy_value.is_evaluated());
+    assert_eq!(*lazy_value, 3);
+    assert_eq!(*lazy_value, 3);
+
+    assert_eq!(evaluator_called_times.get(), 3);
+    assert_eq!(*evicted_values.borrow(), [1, 2]);
+}
+
+#[test]
+fn recomputing_lazy_debounced_invalidate_unevaluated() {
+    let clock = MockClock::new();
+    let evaluator_called_times = Cell::new(0);
+
+    let mut lazy_value = RecomputingLazy::new_debounced(|| {
+        evaluator_called_times.set(evaluator_called_times.get() + 1);
+        evaluator_called_times.get()
+    }, Duration::from_secs(1), &clock);
+
+    lazy_value.invalidate();
+
+    assert_eq!(*lazy_value, 1);
+
+    lazy_value.invalidate();
+
+    assert_eq!(*lazy_value, 2);
+    assert_eq!(evaluator_called_times.get(), 2);
+}
+
+#[test]
+fn lazy_display() {
+    let evaluator_called_times = Cell::new(0);
+
+    let lazy_number = Lazy::new(|| {
+        evaluator_called_times.set(evaluator_called_times.get() + 1);
+        42
+    });
+    let lazy_string = Lazy::new(|| {
+        evaluator_called_times.set(evaluator_called_times.get() + 1);
+        String::from("sloth")
+    });
+
+    assert_eq!(format!("{}", lazy_number), "42");
+    assert_eq!(format!("{:>4}", lazy_number), "  42");
+    assert_eq!(lazy_string.to_string(), "sloth");
+    assert_eq!(format!("{}, {:.3}", lazy_string, lazy_string), "sloth, slo");
+
+    assert_eq!(evaluator_called_times.get(), 2);
+}
+
+#[test]
+fn lazy_into_result_evaluated() {
+    let evaluator_called_times = Cell::new(0);
+    let lazy_value = Lazy::new(|| {
+        evaluator_called_times.set(evaluator_called_times.get() + 1);
+        vec![1, 2, 3]
+    });
+
+    assert_eq!(lazy_value.len(), 3);
+
+    match lazy_value.into_result() {
+        Ok(value) => assert_eq!(value, [1, 2, 3]),
+        Err(_)    => panic!("evaluated instance must yield Ok")
+    }
+
+    assert_eq!(evaluator_called_times.get(), 1);
+}
+
+#[test]
+fn lazy_into_result_unevaluated() {
+    let evaluator_called_times = Cell::new(0);
+    let lazy_value = Lazy::new(|| {
+        evaluator_called_times.set(evaluator_called_times.get() + 1);
+        vec![1, 2, 3]
+    });
+
+    let evaluator = match lazy_value.into_result() {
+        Ok(_)          => panic!("unevaluated instance must yield Err"),
+        Err(evaluator) => evaluator.expect("armed instance must yield its evaluator")
+    };
+
+    assert_eq!(evaluator_called_times.get(), 0);
+    assert_eq!(*Lazy::new(evaluator), [1, 2, 3]);
+    assert_eq!(evaluator_called_times.get(), 1);
+}
+
+#[test]
+fn lazy_into_result_unarmed() {
+    let mut lazy_value = Lazy::new(|| 42);
+
+    lazy_value.take_evaluator();
+
+    assert!(matches!(lazy_value.into_result(), Err(None)));
+}
+
+#[test]
+fn lazy_reusable_retries_after_panic() {
+    let evaluator_called_times = Cell::new(0);
+    let lazy_value = Lazy::new_reusable(|| {
+        evaluator_called_times.set(evaluator_called_times.get() + 1);
+
+        if evaluator_called_times.get() < 3 {
+            panic!("evaluator failed");
+        }
+
+        vec![1, 2, 3]
+    });
+
+    for _ in 0..2 {
+        let access_result = panic::catch_unwind(AssertUnwindSafe(|| lazy_value.len()));
+
+        assert!(access_result.is_err());
+        assert!(!lazy_value.is_evaluated());
+    }
+
+    assert_eq!(*lazy_value, [1, 2, 3]);
+    assert_eq!(*lazy_value, [1, 2, 3]);
+    assert_eq!(evaluator_called_times.get(), 3);
+}
+
+#[test]
+#[should_panic(expected = "evaluator panicked during a previous access")]
+fn lazy_access_after_evaluator_panic() {
+    let lazy_value = Lazy::new(|| -> i32 { panic!("evaluator failed") });
+
+    let access_result = panic::catch_unwind(AssertUnwindSafe(|| *lazy_value));
+
+    assert!(access_result.is_err());
+
+    let _value = *lazy_value;
+}
+
+type SelfReferencingLazy = Rc<BoxedLazy<i32>>;
+
+fn make_self_referencing_lazy(evaluator_called_times: &Rc<Cell<usize>>) -> SelfReferencingLazy {
+    let lazy_weak_cell: Rc<OnceCell<Weak<BoxedLazy<i32>>>> = Rc::new(OnceCell::new());
+
+    let lazy_value = Rc::new(Lazy::new_boxed({
+        let lazy_weak_cell         = Rc::clone(&lazy_weak_cell);
+        let evaluator_called_times = Rc::clone(evaluator_called_times);
+
+        move || {
+            evaluator_called_times.set(evaluator_called_times.get() + 1);
+
+            let lazy_value = lazy_weak_cell.get().and_then(Weak::upgrade).unwrap();
+
+            assert!(lazy_value.is_evaluating());
+            assert_eq!(lazy_value.state(), LazyState::Evaluating);
+            assert_eq!(lazy_value.peek(), None);
+
+            **lazy_value + 1
+        }
+    }));
+
+    lazy_weak_cell.set(Rc::downgrade(&lazy_value)).ok();
+
+    lazy_value
+}
+
+#[test]
+#[should_panic(expected = "reentrant evaluation of Lazy detected")]
+fn lazy_reentrant_evaluation() {
+    let lazy_value = make_self_referencing_lazy(&Rc::new(Cell::new(0)));
+
+    let _value = **lazy_value;
+}
+
+#[test]
+fn lazy_is_evaluating_reset_after_reentrant_panic() {
+    let evaluator_called_times = Rc::new(Cell::new(0));
+    let lazy_value = make_self_referencing_lazy(&evaluator_called_times);
+
+    assert!(!lazy_value.is_evaluating());
+
+    let access_result = panic::catch_unwind(AssertUnwindSafe(|| **lazy_value));
+
+    assert!(access_result.is_err());
+    assert!(!lazy_value.is_evaluating());
+    assert_eq!(lazy_value.state(), LazyState::Unarmed);
+    assert_eq!(evaluator_called_times.get(), 1);
+}
+
+#[test]
+fn ttl_lazy_on_evict() {
+    let clock = MockClock::new();
+    let evicted_values = RefCell::new(Vec::new());
+    let evaluator_called_times = Cell::new(0);
+
+    let mut lazy_value = TtlLazy::with_clock(|| {
+        evaluator_called_times.set(evaluator_called_times.get() + 1);
+        evaluator_called_times.get()
+    }, Duration::from_secs(10), &clock);
+
+    lazy_value.on_evict(|value| evicted_values.borrow_mut().push(*value));
+
+    assert_eq!(*lazy_value.get(), 1);
+    assert!(evicted_values.borrow().is_empty());
+
+    clock.advance(Duration::from_secs(10));
+
+    assert_eq!(*lazy_value.get(), 2);
+    assert_eq!(*lazy_value.get(), 2);
+    assert_eq!(*evicted_values.borrow(), [1]);
+
+    clock.advance(Duration::from_secs(10));
+
+    assert_eq!(*lazy_value.get_mut(), 3);
+    assert_eq!(*evicted_values.borrow(), [1, 2]);
+
+    drop(lazy_value);
+
+    assert_eq!(*evicted_values.borrow(), [1, 2]);
+}
+
+#[test]
+fn recomputing_lazy_on_evict() {
+    let evicted_values = RefCell::new(Vec::new());
+    let mut generation = 0;
+
+    let mut lazy_value = RecomputingLazy::new(move || {
+        generation += 1;
+        generation
+    });
+
+    lazy_value.on_evict(|value| evicted_values.borrow_mut().push(*value));
+
+    lazy_value.invalidate();
+
+    assert!(evicted_values.borrow().is_empty());
+
+    assert_eq!(*lazy_value, 1);
+
+    lazy_value.invalidate();
+    lazy_value.invalidate();
+
+    assert_eq!(*evicted_values.borrow(), [1]);
+
+    *lazy_value += 10;
+    lazy_value.invalidate();
+
+    assert_eq!(*evicted_values.borrow(), [1, 12]);
+}
+
+#[test]
+fn lru_lazy_pool_on_evict() {
+    let evicted_values = RefCell::new(Vec::new());
+    let mut pool = LruLazyPool::new(2);
+
+    pool.on_evict(|value: &i32| evicted_values.borrow_mut().push(*value));
+
+    for key in 0..3 {
+        pool.insert(key, move || key * 10);
+    }
+
+    pool.get(&0);
+    pool.get(&1);
+
+    assert!(RefCell::borrow(&evicted_values).is_empty());
+
+    pool.get(&2);
+
+    assert_eq!(*RefCell::borrow(&evicted_values), [0]);
+
+    pool.get(&2);
+    pool.get(&0);
+
+    assert_eq!(*RefCell::borrow(&evicted_values), [0, 10]);
+
+    pool.insert(2, || 30);
+    drop(pool);
+
+    assert_eq!(*RefCell::borrow(&evicted_values), [0, 10]);
+}
+
+#[test]
+fn lazy_get_or_set_unevaluated() {
+    let evaluator_called_times = Cell::new(0);
+
+    let lazy_value = Lazy::new(|| {
+        evaluator_called_times.set(evaluator_called_times.get() + 1);
+        String::from("evaluated")
+    });
+
+    assert_eq!(lazy_value.get_or_set(String::from("set")), "set");
+    assert!(lazy_value.is_evaluated());
+    assert_eq!(*lazy_value, "set");
+    assert_eq!(evaluator_called_times.get(), 0);
+}
+
+#[test]
+fn lazy_get_or_set_evaluated() {
+    let drop_count = Cell::new(0);
+
+    let lazy_value = Lazy::new(|| (1, SomethingDropCounting{drop_count: &drop_count}));
+
+    lazy_value.force();
+
+    let value = lazy_value.get_or_set((2, SomethingDropCounting{drop_count: &drop_count}));
+
+    assert_eq!(value.0, 1);
+    assert_eq!(drop_count.get(), 1);
+}
+
+#[test]
+fn lazy_get_or_set_drops_evaluator() {
+    let drop_count = Cell::new(0);
+    let something = SomethingDropCounting{drop_count: &drop_count};
+
+    let lazy_value = Lazy::new(move || {
+        let _something = something;
+        1
+    });
+
+    assert_eq!(*lazy_value.get_or_set(2), 2);
+    assert_eq!(drop_count.get(), 1);
+    assert_eq!(*lazy_value.get_or_set(3), 2);
+}
+
+#[test]
+fn lazy_get_or_set_unarmed() {
+    let mut lazy_value = Lazy::new(|| 1);
+
+    lazy_value.take_evaluator();
+
+    assert_eq!(*lazy_value.get_or_set(2), 2);
+    assert_eq!(lazy_value.state(), LazyState::Evaluated);
+}
+
+#[test]
+fn lazy_combine_all() {
+    let evaluator_called_times = Cell::new(0);
+
+    let lazy_parts: Vec<_> = (1..=4)
+        .map(|part| {
+            let evaluator_called_times = &evaluator_called_times;
+
+            Lazy::new(move || {
+                evaluator_called_times.set(evaluator_called_times.get() + 1);
+                part
+            })
+        })
+        .collect();
+
+    let reduce_called_times = Cell::new(0);
+    let lazy_product = Lazy::combine_all(lazy_parts, 1, |product, part| {
+        reduce_called_times.set(reduce_called_times.get() + 1);
+        product * part
+    });
+
+    assert_eq!(evaluator_called_times.get(), 0);
+    assert_eq!(reduce_called_times.get(), 0);
+
+    assert_eq!(*lazy_product, 24);
+    assert_eq!(*lazy_product, 24);
+
+    assert_eq!(evaluator_called_times.get(), 4);
+    assert_eq!(reduce_called_times.get(), 4);
+}
+
+#[test]
+fn lazy_combine_all_partially_evaluated() {
+    let lazy_strings = vec![
+        Lazy::new_boxed(|| String::from("a")),
+        Lazy::new_boxed(|| String::from("b")),
+        Lazy::new_boxed(|| String::from("c"))
+    ];
+
+    lazy_strings[1].force();
+
+    let lazy_string = Lazy::combine_all(lazy_strings, String::from(">"), |result, part| result + &part);
+
+    assert_eq!(*lazy_string, ">abc");
+}
+
+#[test]
+fn lazy_combine_all_empty() {
+    let lazy_value = Lazy::combine_all(Vec::<Lazy<i32>>::new(), 42, |_, _| unreachable!());
+
+    assert_eq!(*lazy_value, 42);
+}
+
+#[test]
+fn lazy_and_then() {
+    let outer_called_times = Cell::new(0);
+    let f_called_times = Cell::new(0);
+    let inner_called_times = Cell::new(0);
+
+    let lazy_value = Lazy::new(|| {
+        outer_called_times.set(outer_called_times.get() + 1);
+        6
+    });
+
+    let lazy_chained = lazy_value.and_then(|value| {
+        f_called_times.set(f_called_times.get() + 1);
+
+        let inner_called_times = &inner_called_times;
+
+        Lazy::new(move || {
+            inner_called_times.set(inner_called_times.get() + 1);
+            value * 7
+        })
+    });
+
+    assert_eq!(outer_called_times.get(), 0);
+    assert_eq!(f_called_times.get(), 0);
+    assert_eq!(inner_called_times.get(), 0);
+
+    assert_eq!(*lazy_chained, 42);
+    assert_eq!(*lazy_chained, 42);
+
+    assert_eq!(outer_called_times.get(), 1);
+    assert_eq!(f_called_times.get(), 1);
+    assert_eq!(inner_called_times.get(), 1);
+}
+
+#[test]
+fn lazy_and_then_never_accessed() {
+    let any_called = Cell::new(false);
+
+    {
+        let _lazy_chained = Lazy::new(|| {
+            any_called.set(true);
+            String::from("moo")
+        }).and_then(|value| {
+            any_called.set(true);
+
+            let any_called = &any_called;
+
+            Lazy::new(move || {
+                any_called.set(true);
+                value.len()
+            })
+        });
+    }
+
+    assert!(!any_called.get());
+}
+
+#[test]
+fn lazy_and_then_evaluated_inner() {
+    let lazy_inner = Lazy::new(|| 1);
+
+    lazy_inner.force();
+
+    let lazy_chained = Lazy::new(|| 2).and_then(|value| lazy_inner.map(move |inner_value| inner_value + value));
+
+    assert_eq!(*lazy_chained, 3);
+}
+
+#[test]
+fn lazy_as_dyn_display() {
+    let evaluator_called_times = Cell::new(0);
+
+    let lazy_number = Lazy::new(|| {
+        evaluator_called_times.set(evaluator_called_times.get() + 1);
+        1.5
+    });
+    let lazy_string = Lazy::new(|| {
+        evaluator_called_times.set(evaluator_called_times.get() + 1);
+        String::from("moo")
+    });
+
+    let displayables = [lazy_number.as_dyn_display(), lazy_string.as_dyn_display()];
+
+    assert_eq!(evaluator_called_times.get(), 2);
+
+    assert_eq!(format!("{} {}", displayables[0], displayables[1]), "1.5 moo");
+    assert_eq!(format!("{:>5}", displayables[1]), "  moo");
+    assert_eq!(format!("{}", lazy_number.as_dyn_display()), "1.5");
+
+    assert_eq!(evaluator_called_times.get(), 2);
+}
+
+#[test]
+fn lazy_into_iter() {
+    let evaluator_called_times = Cell::new(0);
+
+    let lazy_value = Lazy::new(|| {
+        evaluator_called_times.set(evaluator_called_times.get() + 1);
+        String::from("moo")
+    });
+
+    let mut iter = lazy_value.into_iter();
+
+    assert_eq!(evaluator_called_times.get(), 0);
+    assert_eq!(iter.len(), 1);
+
+    assert_eq!(iter.next().as_deref(), Some("moo"));
+    assert_eq!(evaluator_called_times.get(), 1);
+
+    assert_eq!(iter.len(), 0);
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+    assert_eq!(evaluator_called_times.get(), 1);
+}
+
+#[test]
+fn lazy_into_iter_collect() {
+    let evaluator_called_times = Cell::new(0);
+
+    let lazy_value = Lazy::new(|| {
+        evaluator_called_times.set(evaluator_called_times.get() + 1);
+        42
+    });
+
+    let values: Vec<_> = lazy_value.into_iter().collect();
+
+    assert_eq!(values, [42]);
+    assert_eq!(evaluator_called_times.get(), 1);
+}
+
+#[test]
+fn lazy_into_iter_flat_map() {
+    let evaluator_called_times = Cell::new(0);
+
+    let lazy_values: Vec<_> = (1..=3)
+        .map(|value| {
+            let evaluator_called_times = &evaluator_called_times;
+
+            Lazy::new(move || {
+                evaluator_called_times.set(evaluator_called_times.get() + 1);
+                value * 10
+            })
+        })
+        .collect();
+
+    lazy_values[0].force();
+
+    let values: Vec<_> = lazy_values.into_iter().flatten().collect();
+
+    assert_eq!(values, [10, 20, 30]);
+    assert_eq!(evaluator_called_times.get(), 3);
+
+    let mut total = 0;
+    for value in Lazy::new(|| 5) {
+        total += value;
+    }
+
+    assert_eq!(total, 5);
+}
+
+#[test]
+fn lazy_into_iter_not_advanced() {
+    let evaluator_called_times = Cell::new(0);
+
+    let lazy_value = Lazy::new(|| {
+        evaluator_called_times.set(evaluator_called_times.get() + 1);
+        42
+    });
+
+    let iter = lazy_value.into_iter();
+
+    assert_eq!(iter.size_hint(), (1, Some(1)));
+    assert_eq!(evaluator_called_times.get(), 0);
+}
+
+//
+// Service
+//
+
+fn is_borrow_eq<T, Q>(borrowed: &Q, expected: &T) -> bool
+    where   T: PartialEq,
+            Q: Borrow<T>
+{
+    borrowed.borrow() == expected.borrow()
+}
+
+//
+// Service types
+//
+
+struct SomethingDroppable<'a> {
+    was_dropped: &'a mut bool
+}
+
+impl Drop for SomethingDroppable<'_> {
+    fn drop(&mut self) {
+        assert!(!*self.was_dropped, "was_dropped must initially be false");
+
+        *self.was_dropped = true;
+    }
+}
+
+struct SomethingDropCounting<'a> {
+    drop_count: &'a Cell<usize>
+}
+
+impl Drop for SomethingDropCounting<'_> {
+    fn drop(&mut self) {
+        self.drop_count.set(self.drop_count.get() + 1);
+    }
+}
+
+struct SomethingRcDropCounting {
+    drop_count: Rc<Cell<usize>>
+}
+
+impl Drop for SomethingRcDropCounting {
+    fn drop(&mut self) {
+        self.drop_count.set(self.drop_count.get() + 1);
+    }
+}
+
+#[derive(Clone)]
+struct SomethingDropLogging<'a> {
+    name:     &'static str,
+    drop_log: &'a RefCell<Vec<&'static str>>
+}
+
+impl Drop for SomethingDropLogging<'_> {
+    fn drop(&mut self) {
+        self.drop_log.borrow_mut().push(self.name);
     }
 }
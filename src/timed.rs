@@ -0,0 +1,146 @@
+use std::cell::Cell;
+use std::ops::{
+    Deref,
+    DerefMut
+};
+use std::time::Duration;
+
+use crate::Lazy;
+
+//
+// Interface
+//
+
+//
+// struct TimedLazy<T, Eval>: Deref<Target = T> + DerefMut + AsRef<T> + AsMut<T>
+//
+
+/// A [`Lazy<T, Eval>`](struct.Lazy.html), which records how long its evaluation took,
+/// obtained via [`Lazy::timed()`](struct.Lazy.html#method.timed).
+///
+/// The duration of evaluation can be read via [`eval_duration()`](struct.TimedLazy.html#method.eval_duration),
+/// e.g. to be reported to a profiling dashboard, without any external crates.
+/// ```
+/// use sloth::Lazy;
+///
+/// let lazy_value = Lazy::timed(|| (1..=1000).sum::<u32>());
+///
+/// assert!(lazy_value.eval_duration().is_none());
+///
+/// assert_eq!(*lazy_value, 500500);
+///
+/// println!("evaluation took {:?}", lazy_value.eval_duration().unwrap());
+/// ```
+pub struct TimedLazy<T, Eval>
+    where Eval: FnOnce() -> T
+{
+    lazy:               Lazy<T, Eval>,
+    eval_duration_cell: Cell<Option<Duration>>
+}
+
+//
+// Trait impls
+//
+
+impl<T, Eval> Deref for TimedLazy<T, Eval>
+    where Eval: FnOnce() -> T
+{
+    type Target = T;
+
+    /// Immutable dereference, allowing access to the contained value.
+    ///
+    /// This will invoke evaluator function and record its duration
+    /// if the value was not previously accessed.
+    fn deref(&self) -> &T {
+        self.access_timed()
+    }
+}
+
+impl<T, Eval> DerefMut for TimedLazy<T, Eval>
+    where Eval: FnOnce() -> T
+{
+    /// Mutable dereference, allowing access to the contained value.
+    ///
+    /// This will invoke evaluator function and record its duration
+    /// if the value was not previously accessed.
+    fn deref_mut(&mut self) -> &mut T {
+        self.access_timed();
+
+        &mut self.lazy
+    }
+}
+
+impl<T, Eval> AsRef<T> for TimedLazy<T, Eval>
+    where Eval: FnOnce() -> T
+{
+    /// Immutably borrows the evaluation result.
+    ///
+    /// This will invoke evaluator function and record its duration
+    /// if the value was not previously accessed.
+    fn as_ref(&self) -> &T {
+        self
+    }
+}
+
+impl<T, Eval> AsMut<T> for TimedLazy<T, Eval>
+    where Eval: FnOnce() -> T
+{
+    /// Mutably borrows the evaluation result.
+    ///
+    /// This will invoke evaluator function and record its duration
+    /// if the value was not previously accessed.
+    fn as_mut(&mut self) -> &mut T {
+        self
+    }
+}
+
+//
+// Methods
+//
+
+impl<T, Eval> TimedLazy<T, Eval>
+    where Eval: FnOnce() -> T
+{
+    //
+    // Interface
+    //
+
+    /// Returns the duration of evaluation, if the value has already been evaluated,
+    /// otherwise returns `None`.
+    ///
+    /// This does **not** invoke evaluator function.
+    #[must_use]
+    pub fn eval_duration(&self) -> Option<Duration> {
+        self.eval_duration_cell.get()
+    }
+
+    /// Consumes this instance and returns the underlying [`Lazy<T, Eval>`](struct.Lazy.html),
+    /// discarding the recorded duration.
+    ///
+    /// This does **not** invoke evaluator function.
+    #[must_use]
+    pub fn into_lazy(self) -> Lazy<T, Eval> {
+        self.lazy
+    }
+
+    //
+    // Service
+    //
+
+    pub(crate) fn new(lazy: Lazy<T, Eval>) -> Self {
+        Self{
+            lazy,
+            eval_duration_cell: Cell::new(None)
+        }
+    }
+
+    fn access_timed(&self) -> &T {
+        let (value, eval_duration) = self.lazy.access_timed();
+
+        if eval_duration.is_some() {
+            self.eval_duration_cell.set(eval_duration);
+        }
+
+        value
+    }
+}
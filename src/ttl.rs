@@ -0,0 +1,203 @@
+use std::time::{
+    Duration,
+    Instant
+};
+
+use crate::{
+    Clock,
+    SystemClock
+};
+
+//
+// Constants
+//
+
+const EXPECT_VALUE_ENTRY_PRESENT: &str = "value_entry must be present after refresh";
+
+//
+// Types
+//
+
+type EvictCallback<'a, T> = Box<dyn Fn(&T) + 'a>;
+
+//
+// Interface
+//
+
+//
+// struct TtlLazy<'a, T, Eval, C>
+//
+
+/// Contains a value of some type `T`, lazily evaluated using a reusable function
+/// or a closure (`Fn() -> T`), which expires after a fixed time to live.
+///
+/// The value is evaluated the first time it is accessed and cached. Accessing it
+/// after the time to live has elapsed since its evaluation invokes the evaluator again.
+///
+/// Since an expired value may be replaced on access, `TtlLazy` provides access to its value
+/// via methods taking `&mut self`, rather than via dereference.
+///
+/// Time is measured by a [`Clock`](trait.Clock.html), which is [`SystemClock`](struct.SystemClock.html)
+/// for instances constructed with [`new()`](struct.TtlLazy.html#method.new). A different clock,
+/// e.g. a [`MockClock`](struct.MockClock.html) in tests, can be supplied via
+/// [`with_clock()`](struct.TtlLazy.html#method.with_clock).
+/// ```
+/// use std::time::Duration;
+///
+/// use sloth::TtlLazy;
+///
+/// let mut lazy_value = TtlLazy::new(|| "refreshed".to_string(), Duration::from_secs(60));
+///
+/// assert!(lazy_value.is_expired());
+/// assert_eq!(lazy_value.get(), "refreshed"); // evaluator is invoked here
+/// assert_eq!(lazy_value.get(), "refreshed"); // but not here, unless a minute has passed
+/// ```
+///
+/// A callback, set with [`on_evict()`](struct.TtlLazy.html#method.on_evict), is invoked
+/// with each expired value right before it is replaced, which allows releasing
+/// any resources associated with it.
+pub struct TtlLazy<'a, T, Eval, C = SystemClock>
+    where Eval: Fn() -> T,
+          C:    Clock
+{
+    evaluator:    Eval,
+    time_to_live: Duration,
+    clock:        C,
+    value_entry:  Option<(T, Instant)>,
+    on_evict:     Option<EvictCallback<'a, T>>
+}
+
+//
+// Methods
+//
+
+impl<T, Eval> TtlLazy<'_, T, Eval>
+    where Eval: Fn() -> T
+{
+    /// Constructs a lazy `T` instance, whose value, if needed, will later be
+    /// obtained from `evaluator` and cached for `time_to_live`, as measured by
+    /// [`SystemClock`](struct.SystemClock.html).
+    ///
+    /// This does **not** invoke evaluator function.
+    pub fn new(evaluator: Eval, time_to_live: Duration) -> Self {
+        Self::with_clock(evaluator, time_to_live, SystemClock)
+    }
+}
+
+impl<'a, T, Eval, C> TtlLazy<'a, T, Eval, C>
+    where Eval: Fn() -> T,
+          C:    Clock
+{
+    //
+    // Interface
+    //
+
+    /// Constructs a lazy `T` instance, whose value, if needed, will later be
+    /// obtained from `evaluator` and cached for `time_to_live`, as measured by `clock`.
+    ///
+    /// This does **not** invoke evaluator function.
+    pub fn with_clock(evaluator: Eval, time_to_live: Duration, clock: C) -> Self {
+        Self{
+            evaluator,
+            time_to_live,
+            clock,
+            value_entry: None,
+            on_evict:    None
+        }
+    }
+
+    /// Immutably borrows the evaluation result.
+    ///
+    /// This will invoke evaluator function if the value has not been evaluated yet
+    /// or if it has expired.
+    pub fn get(&mut self) -> &T {
+        self.refresh();
+
+        &self.value_entry.as_ref().expect(EXPECT_VALUE_ENTRY_PRESENT).0
+    }
+
+    /// Mutably borrows the evaluation result.
+    ///
+    /// Modifying the value does not extend its time to live.
+    ///
+    /// This will invoke evaluator function if the value has not been evaluated yet
+    /// or if it has expired.
+    pub fn get_mut(&mut self) -> &mut T {
+        self.refresh();
+
+        &mut self.value_entry.as_mut().expect(EXPECT_VALUE_ENTRY_PRESENT).0
+    }
+
+    /// Checks whether the next access to the value will invoke evaluator function,
+    /// i.e. whether the value has not been evaluated yet or has expired.
+    ///
+    /// This does **not** invoke evaluator function.
+    #[must_use]
+    pub fn is_expired(&self) -> bool {
+        match &self.value_entry {
+            Some((_, evaluated_at)) => self.clock.now().saturating_duration_since(*evaluated_at) >= self.time_to_live,
+            None                    => true
+        }
+    }
+
+    /// Sets `callback` to be invoked with the expired value whenever it is evicted
+    /// to be replaced by a newly evaluated one, replacing a previously set callback, if any.
+    ///
+    /// The value held by this instance when it is dropped is **not** passed to `callback`.
+    ///
+    /// This does **not** invoke evaluator function.
+    /// ```
+    /// use std::cell::RefCell;
+    /// use std::time::Duration;
+    ///
+    /// use sloth::{
+    ///     TtlLazy,
+    ///     MockClock
+    /// };
+    ///
+    /// let clock = MockClock::new();
+    /// let evicted_values = RefCell::new(Vec::new());
+    /// let mut lazy_value = TtlLazy::with_clock(|| 42, Duration::from_secs(10), &clock);
+    ///
+    /// lazy_value.on_evict(|value| evicted_values.borrow_mut().push(*value));
+    ///
+    /// lazy_value.get();
+    /// clock.advance(Duration::from_secs(10));
+    /// lazy_value.get(); // the expired value is evicted here
+    ///
+    /// assert_eq!(*evicted_values.borrow(), [42]);
+    /// ```
+    pub fn on_evict<F>(&mut self, callback: F)
+        where F: Fn(&T) + 'a
+    {
+        self.on_evict = Some(Box::new(callback));
+    }
+
+    /// Returns the time to live of the evaluated value.
+    ///
+    /// This does **not** invoke evaluator function.
+    #[must_use]
+    pub fn time_to_live(&self) -> Duration {
+        self.time_to_live
+    }
+
+    //
+    // Service
+    //
+
+    fn refresh(&mut self) {
+        if self.is_expired() {
+            self.evict();
+
+            let value = (self.evaluator)();
+
+            self.value_entry = Some((value, self.clock.now()));
+        }
+    }
+
+    fn evict(&mut self) {
+        if let (Some((value, _)), Some(on_evict)) = (self.value_entry.take(), &self.on_evict) {
+            on_evict(&value);
+        }
+    }
+}
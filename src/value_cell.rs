@@ -0,0 +1,140 @@
+#[cfg(not(feature = "safe-only"))]
+use core::cell::RefCell;
+#[cfg(feature = "safe-only")]
+use core::cell::OnceCell;
+
+//
+// Constants
+//
+
+#[cfg(not(feature = "safe-only"))]
+const EXPECT_VALUE_INITIALIZED:  &str = "value in ValueCell must be initialized at this point";
+#[cfg(not(feature = "safe-only"))]
+const EXPECT_CELL_PTR_NOT_NULL: &str = "RefCell in ValueCell as ptr must not be null";
+
+//
+// Interface
+//
+
+//
+// struct ValueCell<T>
+//
+
+/// Storage for the evaluated value of a [`Lazy<T, Eval>`](struct.Lazy.html),
+/// which is initialized at most once via a shared reference and can then be borrowed
+/// for as long as the storage itself.
+///
+/// By default it is backed by a `RefCell<Option<T>>`, whose contents are borrowed
+/// via a raw pointer.
+///
+/// With the `safe-only` feature, it is backed by a `OnceCell<T>` instead
+/// and involves no `unsafe` code.
+pub(crate) struct ValueCell<T> {
+    #[cfg(not(feature = "safe-only"))]
+    cell: RefCell<Option<T>>,
+    #[cfg(feature = "safe-only")]
+    cell: OnceCell<T>
+}
+
+//
+// Methods
+//
+
+#[cfg(not(feature = "safe-only"))]
+impl<T> ValueCell<T> {
+    pub(crate) const fn new() -> Self {
+        Self{
+            cell: RefCell::new(None)
+        }
+    }
+
+    pub(crate) fn with_value(value: T) -> Self {
+        Self{
+            cell: RefCell::new(Some(value))
+        }
+    }
+
+    pub(crate) fn get(&self) -> Option<&T> {
+        // The value is never replaced or dropped via a shared reference once initialized,
+        // and cell is only mutably borrowed by get_or_init() while it is still uninitialized
+        unsafe {
+            self.cell
+                .as_ptr()
+                .as_ref()
+                .expect(EXPECT_CELL_PTR_NOT_NULL)
+                .as_ref()
+        }
+    }
+
+    pub(crate) fn get_or_init<F>(&self, init: F) -> &T
+        where F: FnOnce() -> T
+    {
+        if self.cell.borrow().is_none() {
+            let value = init();
+
+            *self.cell.borrow_mut() = Some(value);
+        }
+
+        self.get().expect(EXPECT_VALUE_INITIALIZED)
+    }
+
+    pub(crate) fn get_mut(&mut self) -> Option<&mut T> {
+        self.cell.get_mut().as_mut()
+    }
+
+    pub(crate) fn take(&mut self) -> Option<T> {
+        self.cell.get_mut().take()
+    }
+
+    pub(crate) fn into_inner(self) -> Option<T> {
+        self.cell.into_inner()
+    }
+
+    pub(crate) fn is_initialized(&self) -> bool {
+        // cell is only mutably borrowed by get_or_init() while storing the value
+        self.cell
+            .try_borrow()
+            .map_or(true, |value_option| value_option.is_some())
+    }
+}
+
+#[cfg(feature = "safe-only")]
+impl<T> ValueCell<T> {
+    pub(crate) const fn new() -> Self {
+        Self{
+            cell: OnceCell::new()
+        }
+    }
+
+    pub(crate) fn with_value(value: T) -> Self {
+        Self{
+            cell: OnceCell::from(value)
+        }
+    }
+
+    pub(crate) fn get(&self) -> Option<&T> {
+        self.cell.get()
+    }
+
+    pub(crate) fn get_or_init<F>(&self, init: F) -> &T
+        where F: FnOnce() -> T
+    {
+        self.cell.get_or_init(init)
+    }
+
+    pub(crate) fn get_mut(&mut self) -> Option<&mut T> {
+        self.cell.get_mut()
+    }
+
+    pub(crate) fn take(&mut self) -> Option<T> {
+        self.cell.take()
+    }
+
+    pub(crate) fn into_inner(self) -> Option<T> {
+        self.cell.into_inner()
+    }
+
+    pub(crate) fn is_initialized(&self) -> bool {
+        self.cell.get().is_some()
+    }
+}
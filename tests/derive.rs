@@ -0,0 +1,79 @@
+//! Checks the code generated by `#[derive(ForceAll)]`.
+//!
+//! Run with `cargo test --features derive --test derive`.
+
+#![cfg(feature = "derive")]
+
+use std::cell::Cell;
+
+use sloth::{
+    Lazy,
+    BoxedLazy,
+    ForceAll
+};
+
+#[derive(ForceAll)]
+struct NamedLazies<'a, FA, FB>
+    where FA: FnOnce() -> i32,
+          FB: FnOnce() -> String
+{
+    number: Lazy<i32, FA>,
+    text:   Lazy<String, FB>,
+    words:  BoxedLazy<Vec<&'a str>>
+}
+
+#[derive(ForceAll)]
+struct TupleLazies(BoxedLazy<i32>, BoxedLazy<bool>);
+
+#[derive(ForceAll)]
+struct NoLazies;
+
+#[test]
+fn force_all_named_fields() {
+    let evaluator_called_times = Cell::new(0);
+    let count_evaluation = || evaluator_called_times.set(evaluator_called_times.get() + 1);
+
+    let lazies = NamedLazies{
+        number: Lazy::new(|| {
+            count_evaluation();
+            42
+        }),
+        text: Lazy::new(|| {
+            count_evaluation();
+            "sloth".to_string()
+        }),
+        words: Lazy::new_boxed(|| vec!["lazy", "sloth"])
+    };
+
+    assert_eq!(*lazies.number, 42);
+    assert_eq!(evaluator_called_times.get(), 1);
+
+    lazies.force_all();
+    lazies.force_all();
+
+    assert_eq!(evaluator_called_times.get(), 2);
+    assert!(lazies.words.is_evaluated());
+
+    assert_eq!(*lazies.number, 42);
+    assert_eq!(*lazies.text, "sloth");
+    assert_eq!(*lazies.words, ["lazy", "sloth"]);
+    assert_eq!(evaluator_called_times.get(), 2);
+}
+
+#[test]
+fn force_all_tuple_fields() {
+    let lazies = TupleLazies(Lazy::new_boxed(|| 42), Lazy::new_boxed(|| true));
+
+    assert!(!lazies.0.is_evaluated());
+    assert!(!lazies.1.is_evaluated());
+
+    lazies.force_all();
+
+    assert!(lazies.0.is_evaluated());
+    assert!(lazies.1.is_evaluated());
+}
+
+#[test]
+fn force_all_unit_struct() {
+    NoLazies.force_all();
+}
@@ -0,0 +1,45 @@
+//! Checks that the core `Lazy` API compiles and works in a `#![no_std]` crate.
+//!
+//! Run with `cargo test --no-default-features --test no_std`.
+
+#![no_std]
+
+use core::cell::Cell;
+
+use sloth::Lazy;
+
+fn answer() -> u32 {
+    42
+}
+
+#[test]
+fn lazy_new_and_deref() {
+    let evaluator_called_times = Cell::new(0);
+
+    let mut lazy_value = Lazy::new(|| {
+        evaluator_called_times.set(evaluator_called_times.get() + 1);
+        [1, 2, 3]
+    });
+
+    assert!(!lazy_value.is_evaluated());
+    assert_eq!(lazy_value.len(), 3);
+
+    lazy_value[0] = 10;
+
+    assert_eq!(*lazy_value, [10, 2, 3]);
+    assert_eq!(evaluator_called_times.get(), 1);
+}
+
+#[test]
+fn lazy_with_evaluator() {
+    let lazy_value: Lazy<u32, fn() -> u32> = Lazy::with_evaluator(answer);
+
+    assert_eq!(*lazy_value, 42);
+}
+
+#[test]
+fn lazy_default_evaluator_is_fn_pointer() {
+    let lazy_value: Lazy<u32> = Lazy::new(answer);
+
+    assert_eq!(*lazy_value, 42);
+}